@@ -0,0 +1,94 @@
+//! Caches a fetched value in the browser's `localStorage`.
+//!
+//! This example only does something useful on the web. Build it for `wasm32-unknown-unknown`
+//! with the `local-storage` feature (e.g. with `trunk`), and serve it from a page containing a
+//! `<canvas id="the_canvas_id">`. Reload the page to see the cached value appear instantly.
+#![cfg_attr(not(target_family = "wasm"), allow(dead_code))]
+
+use eframe::egui;
+use egui_async::{Bind, ContextExt as _};
+
+#[cfg(target_family = "wasm")]
+fn main() {
+    use eframe::wasm_bindgen::JsCast as _;
+
+    wasm_bindgen_futures::spawn_local(async {
+        let canvas = eframe::web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id("the_canvas_id"))
+            .and_then(|element| {
+                element
+                    .dyn_into::<eframe::web_sys::HtmlCanvasElement>()
+                    .ok()
+            })
+            .expect("Failed to find the_canvas_id");
+
+        eframe::WebRunner::new()
+            .start(
+                canvas,
+                eframe::WebOptions::default(),
+                Box::new(|_cc| Ok(Box::new(MyApp::default()))),
+            )
+            .await
+            .expect("Failed to start eframe");
+    });
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn main() {
+    eprintln!("This example uses localStorage and must be built for wasm32-unknown-unknown.");
+}
+
+struct MyApp {
+    my_ip: Bind<String, String>,
+}
+
+impl Default for MyApp {
+    fn default() -> Self {
+        Self {
+            my_ip: Bind::new(true),
+        }
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        ctx.loop_handle(); // <-- REQUIRED
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("egui-async localStorage Demo");
+
+            #[cfg(target_family = "wasm")]
+            if self.my_ip.is_idle() {
+                // Fills instantly from localStorage (if present), then refreshes in the background.
+                self.my_ip.request_ls_cached("egui-async/my_ip", fetch_ip());
+            }
+
+            match self.my_ip.read() {
+                Some(Ok(ip)) => {
+                    ui.label(format!("Your public IP is: {ip}"));
+                }
+                Some(Err(err)) => {
+                    ui.colored_label(egui::Color32::RED, format!("Error: {err}"));
+                }
+                None => {
+                    ui.spinner();
+                }
+            }
+
+            if self.my_ip.is_pending() {
+                ui.label("Revalidating…");
+            }
+        });
+    }
+}
+
+#[cfg(target_family = "wasm")]
+async fn fetch_ip() -> Result<String, String> {
+    reqwest::get("https://icanhazip.com/")
+        .await
+        .map_err(|e| e.to_string())?
+        .text()
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -67,9 +67,10 @@ impl eframe::App for MyApp {
             ui.separator();
 
             let refresh_interval_secs = 10.0;
+            // Also schedules the repaints that keep the countdown below ticking.
             let time_until_refresh = self
                 .random_user
-                .request_every_sec(fetch_random_user, refresh_interval_secs);
+                .request_every_sec_animated(fetch_random_user, refresh_interval_secs);
 
             // `read` only shows data if it's already available.
             if let Some(name) = self.random_user.read() {
@@ -82,14 +83,9 @@ impl eframe::App for MyApp {
                 };
 
                 ui.label(format!(
-                    "Requesting a new random user in {time_until_refresh:.2}s...",
+                    "Requesting a new random user in {}s...",
+                    time_until_refresh.ceil().max(0.0),
                 ));
-
-                // We must manually request a repaint to ensure the UI updates to show the
-                // countdown. `egui` only repaints on user input (like clicks or drags)
-                // or when explicitly requested. `egui-async` automatically requests a repaint
-                // upon future *completion*, but not during the `Pending` state.
-                ui.ctx().request_repaint();
             } else {
                 ui.horizontal(|ui| {
                     ui.label("Fetching random user...");
@@ -0,0 +1,117 @@
+use eframe::egui;
+use egui_async::{Bind, ContextExt as _, ProgressSender};
+use futures::StreamExt as _;
+
+/// Size of each chunk handed to reqwest while streaming the body.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Boilerplate to run an eframe app
+fn main() {
+    let native_options = eframe::NativeOptions::default();
+    eframe::run_native(
+        "egui-async example",
+        native_options,
+        Box::new(|_cc| Ok(Box::new(MyApp::default()))),
+    )
+    .unwrap();
+}
+
+/// Upload progress reported by the background task, in bytes.
+#[derive(Clone, Copy)]
+struct Progress {
+    sent: u64,
+    total: u64,
+}
+
+#[derive(Default)]
+struct MyApp {
+    /// The Bind struct holds the state of our async operation.
+    upload: Bind<String, String>, // Bind<OkType, ErrType>
+    size_mib: u32,
+}
+
+/// Streams `body` to `url` in chunks, reporting the bytes sent through `progress`, and
+/// resolves with the server's response body.
+///
+/// If the connection drops mid-upload, the error is returned and the last reported progress
+/// keeps the number of bytes that were handed off before the failure.
+async fn upload_with_progress(
+    url: &str,
+    body: Vec<u8>,
+    progress: ProgressSender<Progress>,
+) -> Result<String, String> {
+    let total = body.len() as u64;
+    progress.send(Progress { sent: 0, total });
+
+    let chunks: Vec<Vec<u8>> = body.chunks(CHUNK_SIZE).map(<[u8]>::to_vec).collect();
+    let mut sent = 0;
+    let stream = futures::stream::iter(chunks).map(move |chunk| {
+        sent += chunk.len() as u64;
+        progress.send(Progress { sent, total });
+        Ok::<_, std::io::Error>(chunk)
+    });
+
+    let resp = reqwest::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_LENGTH, total)
+        .body(reqwest::Body::wrap_stream(stream))
+        .send()
+        .await
+        .map_err(|e| format!("Upload failed: {e}"))?
+        .error_for_status()
+        .map_err(|e| format!("Server rejected upload: {e}"))?;
+
+    let text = resp.text().await.map_err(|e| e.to_string())?;
+    Ok(format!("Server replied with {} bytes.", text.len()))
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // This must be called every frame to update the internal time
+        // and drive the polling mechanism.
+        ctx.loop_handle(); // <-- REQUIRED
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("egui-async Upload Progress Demo");
+            ui.label("This example streams a generated file to httpbin.org and tracks progress.");
+
+            ui.separator();
+
+            ui.add(egui::Slider::new(&mut self.size_mib, 1..=32).text("MiB"));
+
+            let pending = self.upload.is_pending();
+            if ui
+                .add_enabled(!pending, egui::Button::new("Upload"))
+                .clicked()
+            {
+                let body = vec![0u8; self.size_mib.max(1) as usize * 1024 * 1024];
+                self.upload.request_with_progress(|progress| async move {
+                    upload_with_progress("https://httpbin.org/post", body, progress).await
+                });
+            }
+
+            // Every progress update requests a repaint, so no polling timer is needed.
+            if let Some(&Progress { sent, total }) = self.upload.progress::<Progress>()
+                && total > 0
+            {
+                #[allow(clippy::cast_precision_loss)]
+                let fraction = sent as f32 / total as f32;
+                ui.add(egui::ProgressBar::new(fraction).text(format!(
+                    "{:.1} / {:.1} MiB",
+                    sent as f64 / 1_048_576.0,
+                    total as f64 / 1_048_576.0
+                )));
+            }
+
+            match self.upload.read() {
+                Some(Ok(msg)) => {
+                    ui.label(msg);
+                }
+                Some(Err(err)) => {
+                    ui.colored_label(egui::Color32::RED, err);
+                }
+                None => {}
+            }
+        });
+    }
+}
@@ -3,30 +3,321 @@
 //! This module provides the `Bind` struct, which is the heart of `egui-async`. It acts as a
 //! state machine to manage the lifecycle of a `Future`, from initiation to completion, and
 //! holds the resulting data or error.
-use std::{fmt::Debug, future::Future};
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    future::Future,
+    hash::{DefaultHasher, Hash, Hasher},
+    marker::PhantomData,
+    pin::Pin,
+    sync::{
+        Arc, LazyLock, Mutex, PoisonError,
+        atomic::{AtomicU8, AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
 use atomic_float::AtomicF64;
-use tokio::sync::oneshot;
-use tracing::warn;
+use futures_channel::oneshot;
+use tokio::sync::{mpsc, watch};
+use tracing::{Instrument as _, debug, warn};
 
-/// The `egui` time of the current frame, updated by `ContextExt::loop_handle`.
+use crate::pacer::Pacer;
+
+/// The `egui` time of the current frame, updated by `tick` (usually through
+/// `ContextExt::loop_handle`).
 pub static CURR_FRAME: AtomicF64 = AtomicF64::new(0.0);
-/// The `egui` time of the previous frame, updated by `ContextExt::loop_handle`.
+/// The `egui` time of the previous frame, updated by `tick` (usually through
+/// `ContextExt::loop_handle`).
 pub static LAST_FRAME: AtomicF64 = AtomicF64::new(0.0);
 
-/// A lazily initialized Tokio runtime for executing async tasks on non-WASM targets.
-#[cfg(not(target_family = "wasm"))]
+/// The number of frames started so far, incremented by `tick`.
+///
+/// Edge detection such as `Bind::just_completed` compares frame indices rather than frame
+/// times, so it cannot be thrown off by equal or repeated timestamps.
+pub static FRAME_INDEX: AtomicU64 = AtomicU64::new(0);
+
+/// Advances the frame clock to `now_seconds`, marking the start of a new frame.
+///
+/// `ContextExt::loop_handle` calls this with `egui`'s input time. Without `egui` (e.g. in a
+/// headless test harness or another GUI framework), call it once per frame with a monotonic
+/// time in seconds to drive every `Bind`.
+pub fn tick(now_seconds: f64) {
+    let last_frame = CURR_FRAME.swap(now_seconds, std::sync::atomic::Ordering::Relaxed);
+    LAST_FRAME.store(last_frame, std::sync::atomic::Ordering::Relaxed);
+    FRAME_INDEX.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Returns the index of the current frame. See `FRAME_INDEX`.
+pub(crate) fn frame_index() -> u64 {
+    FRAME_INDEX.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Reads the page's high-resolution clock, in seconds.
+#[cfg(target_family = "wasm")]
+fn performance_now() -> Option<f64> {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now() / 1000.0)
+}
+
+/// Returns the current time in seconds, as used for every `Bind` timestamp.
+///
+/// This is the frame time set by `tick`. On the web, until `tick` is first called (e.g. in a
+/// test or an app that does not use `loop_handle`), the page's performance clock is used
+/// instead, so that time-based methods still work. Each `poll` then counts as a new frame.
+pub(crate) fn now() -> f64 {
+    #[cfg(target_family = "wasm")]
+    // Until `tick` is first called, the frame clock is not being driven.
+    if frame_index() == 0
+        && let Some(now) = performance_now()
+    {
+        return now;
+    }
+
+    CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// The frame clock of a single `egui::Context`, driven by `ContextExt::loop_handle`.
+///
+/// The global clock (`CURR_FRAME`, `LAST_FRAME` and `FRAME_INDEX`) only follows the first
+/// context, so that several contexts with their own `input.time` do not clobber each other.
+/// `Bind`s attached to another context with `Bind::with_ctx` read that context's clock instead.
+#[cfg(feature = "egui")]
+#[derive(Debug, Default)]
+pub(crate) struct FrameClock {
+    /// The time of the current frame.
+    curr: AtomicF64,
+    /// The time of the previous frame.
+    last: AtomicF64,
+    /// The number of frames started so far.
+    index: AtomicU64,
+    /// One more than the `egui` pass number that last advanced the clock, or `0` before that.
+    pass: AtomicU64,
+}
+
+#[cfg(feature = "egui")]
+impl FrameClock {
+    /// Returns the clock of `ctx`, creating it on first use.
+    pub(crate) fn of(ctx: &egui::Context) -> Arc<Self> {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Arc<Self>>(egui::Id::new("egui_async::FrameClock"))
+                .clone()
+        })
+    }
+
+    /// Advances the clock to `now_seconds`, as `tick` does for the global clock.
+    pub(crate) fn tick(&self, now_seconds: f64) {
+        let last_frame = self.curr.swap(now_seconds, Ordering::Relaxed);
+        self.last.store(last_frame, Ordering::Relaxed);
+        self.index.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records that the `egui` pass `pass_nr` started, returning `false` if it already had.
+    pub(crate) fn start_pass(&self, pass_nr: u64) -> bool {
+        self.pass.swap(pass_nr + 1, Ordering::Relaxed) != pass_nr + 1
+    }
+
+    /// Returns the time of the current frame.
+    pub(crate) fn now(&self) -> f64 {
+        self.curr.load(Ordering::Relaxed)
+    }
+
+    /// Returns the time of the previous frame.
+    pub(crate) fn last_frame(&self) -> f64 {
+        self.last.load(Ordering::Relaxed)
+    }
+
+    /// Returns the index of the current frame.
+    pub(crate) fn frame_index(&self) -> u64 {
+        self.index.load(Ordering::Relaxed)
+    }
+}
+
+/// The `egui::Context` a `Bind` is attached to, with that context's frame clock.
+#[cfg(feature = "egui")]
+#[derive(Clone)]
+struct CtxScope {
+    ctx: egui::Context,
+    clock: Arc<FrameClock>,
+}
+
+#[cfg(feature = "egui")]
+impl CtxScope {
+    fn new(ctx: &egui::Context) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            clock: FrameClock::of(ctx),
+        }
+    }
+
+    /// Returns the clock, unless it has not been driven yet.
+    fn driven_clock(&self) -> Option<&FrameClock> {
+        Some(&*self.clock).filter(|clock| clock.frame_index() > 0)
+    }
+}
+
+/// A reading of a frame clock, taken once so that a `poll` does not load it repeatedly.
+#[derive(Clone, Copy, Debug)]
+struct FrameTime {
+    /// The time of the current frame.
+    now: f64,
+    /// The index of the current frame.
+    index: u64,
+}
+
+impl FrameTime {
+    /// Reads the global frame clock.
+    fn global() -> Self {
+        Self {
+            now: now(),
+            index: frame_index(),
+        }
+    }
+}
+
+/// A lazily initialized Tokio runtime for executing async tasks on non-WASM targets, with the
+/// `runtime-tokio` feature.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
 pub static ASYNC_RUNTIME: std::sync::LazyLock<tokio::runtime::Runtime> =
     std::sync::LazyLock::new(|| {
         tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime.")
     });
 
+/// A runtime handle supplied by the application with `init_runtime`.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+static RUNTIME_HANDLE: std::sync::OnceLock<tokio::runtime::Handle> = std::sync::OnceLock::new();
+
+/// Makes every `Bind` spawn its tasks on an existing Tokio runtime instead of `ASYNC_RUNTIME`.
+///
+/// Call this once at startup, before the first request, to share your own runtime (e.g. the
+/// one created by `#[tokio::main]`, with your worker count and database pools) and avoid
+/// creating a second one. If it is never called, `ASYNC_RUNTIME` is created lazily as before.
+///
+/// The runtime must have its time driver enabled (e.g. `Builder::enable_all`), since
+/// timeouts and retry delays rely on it.
+///
+/// Only the first call has an effect; later calls are ignored with a warning.
+///
+/// # Example
+/// ```ignore
+/// #[tokio::main]
+/// async fn main() -> eframe::Result {
+///     egui_async::bind::init_runtime(tokio::runtime::Handle::current());
+///     eframe::run_native(/* ... */)
+/// }
+/// ```
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+pub fn init_runtime(handle: tokio::runtime::Handle) {
+    if RUNTIME_HANDLE.set(handle).is_err() {
+        warn!("init_runtime was called more than once; keeping the first runtime.");
+    }
+}
+
 /// A global holder for the `egui::Context`, used to request repaints from background tasks.
 ///
-/// This is initialized once by `egui::ContextExt::loop_handle`.
+/// This is initialized by the first call to `egui::ContextExt::loop_handle`. A `Bind` attached
+/// to another context with `Bind::with_ctx` repaints that context instead.
 #[cfg(feature = "egui")]
 pub static CTX: std::sync::OnceLock<egui::Context> = std::sync::OnceLock::new();
 
+/// The minimum interval, in seconds, between repaints requested by background tasks.
+#[cfg(feature = "egui")]
+static REPAINT_THROTTLE: AtomicF64 = AtomicF64::new(0.0);
+
+/// Limits how often background tasks may request a repaint.
+///
+/// By default (`Duration::ZERO`) every task completion calls `request_repaint()` immediately.
+/// With a non-zero interval, task-initiated repaints use `request_repaint_after` instead, which
+/// `egui` coalesces, so a burst of completions or a chatty task repaints at most about once
+/// per interval. This keeps streaming UIs smooth without driving the frame rate needlessly high.
+#[cfg(feature = "egui")]
+pub fn set_repaint_throttle(interval: Duration) {
+    REPAINT_THROTTLE.store(interval.as_secs_f64(), std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Where a background task sends its repaint requests: the `egui::Context` of the `Bind` that
+/// spawned it (see `Bind::with_ctx`), or else the global `CTX`.
+#[derive(Clone, Default)]
+pub(crate) struct Repainter {
+    #[cfg(feature = "egui")]
+    ctx: Option<egui::Context>,
+}
+
+impl Repainter {
+    /// Requests a repaint, honoring `set_repaint_throttle`. A no-op without the `egui` feature.
+    #[cfg_attr(not(feature = "egui"), allow(clippy::unused_self))]
+    pub(crate) fn request(&self) {
+        #[cfg(feature = "egui")]
+        if let Some(ctx) = self.ctx.as_ref().or_else(|| CTX.get()) {
+            let throttle = REPAINT_THROTTLE.load(std::sync::atomic::Ordering::Relaxed);
+            if throttle > 0.0 {
+                #[allow(clippy::cast_possible_truncation)]
+                ctx.request_repaint_after_secs(throttle as f32);
+            } else {
+                ctx.request_repaint();
+            }
+        }
+    }
+}
+
+/// Requests a repaint of the global `CTX` on behalf of a background task.
+pub(crate) fn request_repaint() {
+    Repainter::default().request();
+}
+
+/// A connectivity probe consulted before automatic requests are started.
+type OnlineChecker = Box<dyn Fn() -> bool + Send + Sync>;
+
+/// The global connectivity probe, set by `set_online_checker`.
+static ONLINE_CHECKER: std::sync::RwLock<Option<OnlineChecker>> = std::sync::RwLock::new(None);
+
+/// How long to wait before re-checking connectivity after an automatic request was skipped.
+#[cfg(feature = "egui")]
+const OFFLINE_RECHECK_SECS: f32 = 1.0;
+
+/// Sets a global connectivity probe used to gate automatic requests.
+///
+/// While the checker returns `false`, the auto-fetch methods (`state_or_request`,
+/// `read_or_request`, `request_every_sec`, etc.) do not spawn anything. The `Bind` simply
+/// stays as it is and reports `is_offline() == true`. As soon as the checker returns `true`
+/// again, the next call to one of those methods fires the request as usual.
+///
+/// Explicit calls such as `request` and `refresh` are never gated.
+///
+/// Because no task is spawned while offline, nothing is recorded as a failure, so skipped
+/// requests never show up as errors and do not count towards any retry or backoff policy.
+///
+/// # Example
+/// ```ignore
+/// // On WASM, consult the browser's connectivity flag.
+/// egui_async::bind::set_online_checker(|| {
+///     web_sys::window().is_none_or(|w| w.navigator().on_line())
+/// });
+/// ```
+pub fn set_online_checker(checker: impl Fn() -> bool + Send + Sync + 'static) {
+    *ONLINE_CHECKER
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(Box::new(checker));
+}
+
+/// Removes the global connectivity probe, so every automatic request is allowed again.
+pub fn clear_online_checker() {
+    *ONLINE_CHECKER
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+}
+
+/// Returns the result of the global connectivity probe, or `true` if none is set.
+pub fn is_online() -> bool {
+    ONLINE_CHECKER
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .is_none_or(|checker| checker())
+}
+
 /// Represents the execution state of an asynchronous operation managed by `Bind`.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum State {
@@ -40,7 +331,7 @@ pub enum State {
 }
 
 /// Represents the detailed state of a `Bind`, including available data.
-pub enum StateWithData<'a, T, E> {
+pub enum StateWithData<'a, T: ?Sized, E> {
     /// No operation is running.
     Idle,
     /// An operation is currently in-flight.
@@ -51,571 +342,5632 @@ pub enum StateWithData<'a, T, E> {
     Failed(&'a E),
 }
 
-/// A state manager for a single asynchronous operation, designed for use with `egui`.
-///
-/// `Bind` tracks the lifecycle of a `Future` and stores its `Result<T, E>`. It acts as a
-/// bridge between the immediate-mode UI and the background async task, ensuring the UI
-/// can react to changes in state (e.g., show a spinner while `Pending`, display the
-/// result when `Finished`, or show an error).
-pub struct Bind<T, E> {
-    /// The `egui` time of the most recent frame where this `Bind` was polled.
-    drawn_time_last: f64,
-    /// The `egui` time of the second most recent frame where this `Bind` was polled.
-    drawn_time_prev: f64,
-
-    /// The result of the completed async operation. `None` if the task is not `Finished`.
-    pub(crate) data: Option<Result<T, E>>,
-    /// The receiving end of a one-shot channel used to get the result from the background task.
-    /// This is `Some` only when the state is `Pending`.
-    recv: Option<oneshot::Receiver<Result<T, E>>>,
+impl<'a, T: ?Sized, E> StateWithData<'a, T, E> {
+    /// Returns `true` if no operation is running.
+    #[must_use]
+    pub const fn is_idle(&self) -> bool {
+        matches!(self, Self::Idle)
+    }
 
-    /// The current execution state of the async operation.
-    pub(crate) state: State,
-    /// The `egui` time when the most recent operation was started.
-    last_start_time: f64,
-    /// The `egui` time when the most recent operation was completed.
-    last_complete_time: f64,
+    /// Returns `true` if an operation is in-flight.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
 
-    /// If `true`, the `data` from a `Finished` state is preserved even if the `Bind` instance
-    /// is not polled for one or more frames. If `false`, the data is cleared.
-    retain: bool,
+    /// Returns `true` if the operation completed successfully.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        matches!(self, Self::Finished(_))
+    }
 
-    /// A counter for how many times an async operation has been started.
-    times_executed: usize,
-}
+    /// Returns `true` if the operation completed with an error.
+    #[must_use]
+    pub const fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
 
-impl<T, E> Debug for Bind<T, E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut out = f.debug_struct("Bind");
-        let mut out = out
-            .field("state", &self.state)
-            .field("retain", &self.retain)
-            .field("drawn_time_last", &self.drawn_time_last)
-            .field("drawn_time_prev", &self.drawn_time_prev)
-            .field("last_start_time", &self.last_start_time)
-            .field("last_complete_time", &self.last_complete_time)
-            .field("times_executed", &self.times_executed);
+    /// Returns the successful result, if any.
+    #[must_use]
+    pub const fn as_option(&self) -> Option<&'a T> {
+        match self {
+            Self::Finished(data) => Some(data),
+            _ => None,
+        }
+    }
 
-        // Avoid printing the full data/recv content for cleaner debug output.
-        if self.data.is_some() {
-            out = out.field("data", &"Some(...)");
-        } else {
-            out = out.field("data", &"None");
+    /// Returns the error, if the operation failed.
+    #[must_use]
+    pub const fn error(&self) -> Option<&'a E> {
+        match self {
+            Self::Failed(err) => Some(err),
+            _ => None,
         }
+    }
 
-        if self.recv.is_some() {
-            out = out.field("recv", &"Some(...)");
-        } else {
-            out = out.field("recv", &"None");
+    /// Returns the outcome of a completed operation, or `None` while `Idle` or `Pending`.
+    ///
+    /// This handles success and failure in one code path while keeping the borrows, like
+    /// `Bind::read_as_ref`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if let Some(result) = bind.state().result() {
+    ///     log::info!("Fetch finished: {:?}", result.map(|user| &user.name));
+    /// }
+    /// ```
+    #[must_use]
+    pub const fn result(&self) -> Option<Result<&'a T, &'a E>> {
+        match self {
+            Self::Finished(data) => Some(Ok(data)),
+            Self::Failed(err) => Some(Err(err)),
+            Self::Idle | Self::Pending => None,
         }
+    }
 
-        out.finish()
+    /// Projects the successful result with `f`, e.g. to a field, keeping the other states.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if let Some(name) = bind.state().map(|user| user.name.as_str()).as_option() {
+    ///     ui.label(name);
+    /// }
+    /// ```
+    pub fn map<U: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a U) -> StateWithData<'a, U, E> {
+        match self {
+            Self::Idle => StateWithData::Idle,
+            Self::Pending => StateWithData::Pending,
+            Self::Finished(data) => StateWithData::Finished(f(data)),
+            Self::Failed(err) => StateWithData::Failed(err),
+        }
     }
 }
 
-impl<T: 'static, E: 'static> Default for Bind<T, E> {
-    /// Creates a default `Bind` instance in an `Idle` state.
-    ///
-    /// The `retain` flag is set to `false`. This implementation does not require `T` or `E`
-    /// to implement `Default`.
-    fn default() -> Self {
-        Self::new(false)
+/// An owned copy of a `StateWithData`, as returned by `Bind::snapshot`.
+///
+/// Unlike `StateWithData`, it does not borrow the `Bind`, so it can be stored, compared
+/// between frames, or moved into a closure or task.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateSnapshot<T, E> {
+    /// No operation is running.
+    Idle,
+    /// An operation is currently in-flight.
+    Pending,
+    /// An operation has completed with a successful result.
+    Finished(T),
+    /// An operation has completed with an error.
+    Failed(E),
+}
+
+impl<T: Clone, E: Clone> From<StateWithData<'_, T, E>> for StateSnapshot<T, E> {
+    fn from(state: StateWithData<'_, T, E>) -> Self {
+        match state {
+            StateWithData::Idle => Self::Idle,
+            StateWithData::Pending => Self::Pending,
+            StateWithData::Finished(data) => Self::Finished(data.clone()),
+            StateWithData::Failed(err) => Self::Failed(err.clone()),
+        }
     }
 }
 
-/// A trait alias for `Send` on native targets.
+/// What happened to a `Bind` during the current frame, as returned by `Bind::step`.
+pub enum Step<'a, T, E> {
+    /// No operation is running and none could be started (e.g. the app is offline).
+    Idle,
+    /// An operation was started by this call.
+    Started,
+    /// An operation started in an earlier frame (or earlier in this one) is in-flight.
+    Pending,
+    /// The operation finished during the current frame. Reported for the whole frame in which
+    /// the result was received.
+    JustCompleted(&'a Result<T, E>),
+    /// The operation finished in an earlier frame.
+    Finished(&'a Result<T, E>),
+}
+
+/// The background persistence status of a value stored by `Bind::request_local_first`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SyncStatus {
+    /// The value is being synced in the background.
+    Syncing,
+    /// The sync finished successfully.
+    Synced,
+    /// The sync failed. See `Bind::sync_error`.
+    Failed,
+}
+
+/// The timing of a single completed operation, as recorded in a `Bind`'s timing history.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequestTiming {
+    /// The `egui` time when the operation was started.
+    pub start_time: f64,
+    /// The `egui` time when the operation's result was received.
+    pub complete_time: f64,
+    /// Whether the operation finished with `Ok`.
+    pub succeeded: bool,
+}
+
+/// The frame-timing bookkeeping of a `Bind`, as returned by `Bind::debug_timing`.
 ///
-/// On WASM, this trait has no bounds, allowing non-`Send` types to be used in `Bind`
-/// since WASM is single-threaded.
-#[cfg(not(target_family = "wasm"))]
-pub trait MaybeSend: Send {}
-#[cfg(not(target_family = "wasm"))]
-impl<T: Send> MaybeSend for T {}
+/// All times are `egui` times in seconds, meant for diagnostics such as an in-app debug
+/// overlay, or for asserting on the `retain` logic in a test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimingInfo {
+    /// The time of the current frame, as seen by this `Bind`.
+    pub frame_time: f64,
+    /// The time of the previous frame, as seen by this `Bind`.
+    pub last_frame_time: f64,
+    /// The time of the frame in which the `Bind` was last polled.
+    pub last_drawn_time: f64,
+    /// The time of the frame in which the `Bind` was polled before that.
+    pub prev_drawn_time: f64,
+    /// The time when the current state was entered.
+    pub state_entered_time: f64,
+    /// The time when the latest operation was started.
+    pub start_time: f64,
+    /// The time when the latest operation completed.
+    pub complete_time: f64,
+}
 
-/// A trait alias with no bounds on WASM targets.
+/// A handle that observes the completions of a `Bind`, created by `Bind::subscribe`.
 ///
-/// This allows `Bind` to work with `!Send` futures and data types in a single-threaded
-/// web environment.
-#[cfg(target_family = "wasm")]
-pub trait MaybeSend {}
-#[cfg(target_family = "wasm")]
-impl<T> MaybeSend for T {}
+/// Dependents keep a subscription and check `changed` each frame instead of comparing
+/// completion timestamps. Subscriptions are cheap: they share a single generation counter with
+/// their source.
+#[derive(Debug)]
+pub struct BindSubscription {
+    generation: Arc<AtomicU64>,
+    seen: u64,
+}
 
-impl<T: 'static, E: 'static> Bind<T, E> {
-    /// Creates a new `Bind` instance with a specific retain policy.
+impl BindSubscription {
+    /// Returns `true` if the source `Bind` finished an operation (or was filled) since this
+    /// subscription was created or last reported a change.
     ///
-    /// # Parameters
-    /// - `retain`: If `true`, the result of the operation is kept even if the `Bind`
-    ///   is not polled in a frame. If `false`, the result is cleared if not polled
-    ///   for one frame, returning the `Bind` to an `Idle` state.
-    #[must_use]
-    pub const fn new(retain: bool) -> Self {
-        Self {
-            drawn_time_last: 0.0,
-            drawn_time_prev: 0.0,
-            data: None,
-            recv: None,
-            state: State::Idle,
-            last_start_time: 0.0,
-            last_complete_time: f64::MIN, // Set to a very low value to ensure `since_completed` is large initially.
-            retain,
-            times_executed: 0,
+    /// Several completions between two calls are reported as a single change.
+    pub fn changed(&mut self) -> bool {
+        let generation = self.generation.load(Ordering::Acquire);
+        let changed = generation != self.seen;
+        self.seen = generation;
+        changed
+    }
+}
+
+/// The connection status of a live source, such as `Bind::request_sse`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionStatus {
+    /// The first connection attempt is in progress.
+    Connecting,
+    /// The connection is open and receiving values.
+    Open,
+    /// The connection was lost and is being re-established with backoff.
+    Reconnecting,
+    /// The source has stopped for good (e.g. the server rejected the request).
+    Closed,
+}
+
+impl ConnectionStatus {
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Connecting,
+            1 => Self::Open,
+            2 => Self::Reconnecting,
+            _ => Self::Closed,
         }
     }
+}
 
-    /// Internal helper to prepare the state and communication channel for a new async request.
-    #[allow(clippy::type_complexity)]
-    fn prepare_channel(
-        &mut self,
-    ) -> (
-        oneshot::Sender<Result<T, E>>,
-        oneshot::Receiver<Result<T, E>>,
-    ) {
-        self.poll(); // Ensure state is up-to-date before starting.
+/// Controls how values from a live source (such as `Bind::request_sse` or
+/// `BindStream::request_stream`) are delivered when the source produces them faster than the
+/// UI polls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BackpressureMode {
+    /// Only the newest value is presented; values queued since the last poll are discarded.
+    /// This suits telemetry-like sources where only the current value matters.
+    #[default]
+    KeepLatest,
+    /// Every value is presented, one per frame. At most this many values are buffered; when
+    /// the buffer overflows, the oldest values are dropped.
+    KeepAll(usize),
+    /// Every value is presented, one per frame. The source waits until the previous value has
+    /// been taken, so nothing is dropped.
+    Block,
+}
 
-        self.last_start_time = CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed);
-        self.state = State::Pending;
+/// The sending half of a live source's channel, chosen by its `BackpressureMode`.
+pub(crate) enum LiveSender<T> {
+    Unbounded(mpsc::UnboundedSender<T>),
+    Bounded(mpsc::Sender<T>),
+}
 
-        oneshot::channel()
+impl<T> LiveSender<T> {
+    /// Sends a value, waiting for room in `BackpressureMode::Block`. Returns `false` if the
+    /// receiver is gone.
+    pub(crate) async fn send(&self, value: T) -> bool {
+        match self {
+            Self::Unbounded(tx) => tx.send(value).is_ok(),
+            Self::Bounded(tx) => tx.send(value).await.is_ok(),
+        }
     }
 
-    /// Internal async function that awaits the user's future and sends the result back.
-    async fn req_inner<F>(fut: F, tx: oneshot::Sender<Result<T, E>>)
-    where
-        F: Future<Output = Result<T, E>> + 'static,
-        T: MaybeSend,
-    {
-        let result = fut.await;
-        if matches!(tx.send(result), Ok(())) {
-            // If the send was successful, request a repaint to show the new data.
-            #[cfg(feature = "egui")]
-            if let Some(ctx) = CTX.get() {
-                ctx.request_repaint();
-            }
-        } else {
-            // This occurs if the `Bind` was dropped before the future completed.
-            warn!("Future result was dropped because the receiver was gone.");
+    /// Returns `true` if the receiver is gone.
+    #[cfg_attr(not(feature = "sse"), allow(dead_code))]
+    fn is_closed(&self) -> bool {
+        match self {
+            Self::Unbounded(tx) => tx.is_closed(),
+            Self::Bounded(tx) => tx.is_closed(),
         }
     }
+}
 
-    /// Starts an asynchronous operation if the `Bind` is not already `Pending`.
-    ///
-    /// The provided future `f` is spawned onto the appropriate runtime (`tokio` for native,
-    /// `wasm-bindgen-futures` for WASM). The `Bind` state transitions to `Pending`.
-    ///
-    /// This method calls `poll()` internally.
-    pub fn request<Fut>(&mut self, f: Fut)
+/// The receiving half of a live source's channel.
+enum LiveReceiver<T> {
+    Unbounded(mpsc::UnboundedReceiver<T>),
+    Bounded(mpsc::Receiver<T>),
+}
+
+impl<T> LiveReceiver<T> {
+    fn try_recv(&mut self) -> Result<T, mpsc::error::TryRecvError> {
+        match self {
+            Self::Unbounded(rx) => rx.try_recv(),
+            Self::Bounded(rx) => rx.try_recv(),
+        }
+    }
+}
+
+/// The values of a live source (such as `Bind::request_sse` or `BindStream::request_stream`)
+/// that have yet to be presented.
+pub(crate) struct LiveQueue<T> {
+    /// Receives every value produced by the source.
+    rx: LiveReceiver<T>,
+    /// Values waiting to be presented in `BackpressureMode::KeepAll`.
+    backlog: VecDeque<T>,
+    /// How queued values are handled.
+    mode: BackpressureMode,
+    /// Whether the source has ended, i.e. its sender was dropped.
+    ended: bool,
+    /// Requests the repaints that present the rest of the queue.
+    repaint: Repainter,
+}
+
+impl<T> LiveQueue<T> {
+    /// Creates a queue and the sender its source task should use.
+    pub(crate) fn new(mode: BackpressureMode, repaint: Repainter) -> (LiveSender<T>, Self) {
+        let (tx, rx) = if matches!(mode, BackpressureMode::Block) {
+            let (tx, rx) = mpsc::channel(1);
+            (LiveSender::Bounded(tx), LiveReceiver::Bounded(rx))
+        } else {
+            let (tx, rx) = mpsc::unbounded_channel();
+            (LiveSender::Unbounded(tx), LiveReceiver::Unbounded(rx))
+        };
+
+        let queue = Self {
+            rx,
+            backlog: VecDeque::new(),
+            mode,
+            ended: false,
+            repaint,
+        };
+        (tx, queue)
+    }
+
+    /// Takes the next value that has arrived, regardless of the `BackpressureMode`.
+    pub(crate) fn try_recv(&mut self) -> Option<T> {
+        match self.rx.try_recv() {
+            Ok(value) => Some(value),
+            Err(mpsc::error::TryRecvError::Empty) => None,
+            Err(mpsc::error::TryRecvError::Disconnected) => {
+                self.ended = true;
+                None
+            }
+        }
+    }
+
+    /// Returns the value to present this frame, if any, according to the `BackpressureMode`.
+    pub(crate) fn next(&mut self) -> Option<T> {
+        match self.mode {
+            BackpressureMode::KeepLatest => {
+                let mut latest = None;
+                while let Some(value) = self.try_recv() {
+                    latest = Some(value);
+                }
+                latest
+            }
+            BackpressureMode::KeepAll(cap) => {
+                while let Some(value) = self.try_recv() {
+                    self.backlog.push_back(value);
+                }
+                while self.backlog.len() > cap.max(1) {
+                    self.backlog.pop_front();
+                }
+                let next = self.backlog.pop_front();
+                if !self.backlog.is_empty() {
+                    // Present the rest of the backlog on the following frames.
+                    self.repaint.request();
+                }
+                next
+            }
+            BackpressureMode::Block => {
+                let next = self.try_recv();
+                if next.is_some() {
+                    // The source may have another value ready as soon as room is made.
+                    self.repaint.request();
+                }
+                next
+            }
+        }
+    }
+
+    /// Returns `true` once the source has ended and every value has been presented.
+    pub(crate) fn is_drained(&self) -> bool {
+        self.ended && self.backlog.is_empty()
+    }
+}
+
+/// A type-erased progress value reported through a `ProgressSender`.
+type ProgressValue = Box<dyn Any + Send + Sync>;
+
+/// Reports progress from a task started by `Bind::request_with_progress`.
+///
+/// Cloning the sender lets several parts of the task report progress.
+pub struct ProgressSender<P> {
+    tx: mpsc::UnboundedSender<ProgressValue>,
+    repaint: Repainter,
+    _marker: PhantomData<fn(P)>,
+}
+
+impl<P> Clone for ProgressSender<P> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            repaint: self.repaint.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P> Debug for ProgressSender<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressSender")
+            .field("closed", &self.tx.is_closed())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P: Send + Sync + 'static> ProgressSender<P> {
+    /// Reports `progress`, replacing the previously reported value, and requests a repaint.
+    ///
+    /// Does nothing if the `Bind` has moved on (e.g. it was cleared or started another
+    /// operation).
+    pub fn send(&self, progress: P) {
+        if self.tx.send(Box::new(progress)).is_ok() {
+            self.repaint.request();
+        }
+    }
+}
+
+/// A long-lived source that keeps pushing values into a `Bind`.
+struct LiveFeed<T, E> {
+    /// The values waiting to be presented.
+    queue: LiveQueue<Result<T, E>>,
+    /// The source's `ConnectionStatus`, stored as a `u8` and written by the task.
+    status: Arc<AtomicU8>,
+}
+
+#[cfg_attr(not(feature = "sse"), allow(dead_code))]
+impl<T, E> LiveFeed<T, E> {
+    /// Creates a feed and the sender its source task should use.
+    fn new(mode: BackpressureMode, repaint: Repainter) -> (LiveSender<Result<T, E>>, Self) {
+        let (tx, queue) = LiveQueue::new(mode, repaint);
+        let feed = Self {
+            queue,
+            status: Arc::new(AtomicU8::new(ConnectionStatus::Connecting as u8)),
+        };
+        (tx, feed)
+    }
+
+    /// Returns the value to present this frame, if any, according to the `BackpressureMode`.
+    fn next(&mut self) -> Option<Result<T, E>> {
+        self.queue.next()
+    }
+}
+
+/// The outcome of a conditional request made with `Bind::request_conditional`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CondResult<T> {
+    /// The resource is unchanged (e.g. HTTP 304), so the existing data should be kept.
+    NotModified,
+    /// The resource changed. Carries the new data and its new validator token, if any
+    /// (e.g. the `ETag` or `Last-Modified` header).
+    Modified(T, Option<String>),
+}
+
+/// The message a background task sends back to its `Bind`.
+enum Completion<T, E> {
+    /// The result of a regular request.
+    Result(Result<T, E>),
+    /// The result of a conditional request.
+    Conditional(Result<CondResult<T>, E>),
+    /// The request was dropped after exceeding the `Bind`'s default timeout.
+    TimedOut,
+}
+
+/// The first reconnection delay of `Bind::request_sse`.
+#[cfg(feature = "sse")]
+const SSE_RETRY_START: Duration = Duration::from_millis(500);
+/// The maximum reconnection delay of `Bind::request_sse`.
+#[cfg(feature = "sse")]
+const SSE_RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Drives a server-sent events connection, forwarding parsed events until the `Bind` goes away.
+#[cfg(feature = "sse")]
+async fn run_sse<T, E>(
+    url: String,
+    parse: impl Fn(&str) -> Result<T, E>,
+    tx: LiveSender<Result<T, E>>,
+    status: Arc<AtomicU8>,
+    repaint: Repainter,
+) {
+    use futures_util::StreamExt as _;
+    use reqwest_eventsource::{Event, EventSource, retry::ExponentialBackoff};
+
+    let mut source = EventSource::get(url.as_str());
+    source.set_retry_policy(Box::new(ExponentialBackoff::new(
+        SSE_RETRY_START,
+        2.0,
+        Some(SSE_RETRY_MAX),
+        None,
+    )));
+
+    while let Some(event) = source.next().await {
+        if tx.is_closed() {
+            break;
+        }
+
+        match event {
+            Ok(Event::Open) => {
+                status.store(ConnectionStatus::Open as u8, Ordering::Release);
+                repaint.request();
+            }
+            Ok(Event::Message(message)) => {
+                if !tx.send(parse(&message.data)).await {
+                    break;
+                }
+                repaint.request();
+            }
+            Err(err) => {
+                warn!("SSE connection to {url} failed: {err}");
+                status.store(ConnectionStatus::Reconnecting as u8, Ordering::Release);
+                repaint.request();
+            }
+        }
+    }
+
+    source.close();
+    status.store(ConnectionStatus::Closed as u8, Ordering::Release);
+    repaint.request();
+}
+
+/// The error produced when `Bind::request_with_budget` runs out of time.
+///
+/// Convert it into your own error type by implementing `From<BudgetExhausted<E>>`. An
+/// implementation for `String` is provided.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BudgetExhausted<E> {
+    /// The number of attempts that were started.
+    pub attempts: usize,
+    /// The error of the last attempt, or `None` if it was still running (or had timed out)
+    /// when the budget ran out.
+    pub last_error: Option<E>,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for BudgetExhausted<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Retry budget exhausted after {} attempt(s)",
+            self.attempts
+        )?;
+        match &self.last_error {
+            Some(err) => write!(f, ": {err}"),
+            None => f.write_str(": the last attempt timed out"),
+        }
+    }
+}
+
+impl<E: Debug + std::fmt::Display> std::error::Error for BudgetExhausted<E> {}
+
+impl From<BudgetExhausted<Self>> for String {
+    fn from(err: BudgetExhausted<Self>) -> Self {
+        err.to_string()
+    }
+}
+
+/// Why an operation ended without a result, as reported by `Bind::try_poll`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PollError {
+    /// The background task was dropped before sending its result, e.g. because it panicked or
+    /// the runtime shut down.
+    TaskDropped,
+    /// The `Bind` was `Pending` without a task to wait for. This indicates a bug in
+    /// `egui-async`.
+    ReceiverMissing,
+}
+
+impl std::fmt::Display for PollError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::TaskDropped => "the async task was dropped without sending a result",
+            Self::ReceiverMissing => "the Bind was pending without a task to wait for",
+        })
+    }
+}
+
+impl std::error::Error for PollError {}
+
+/// The pause between two attempts of `Bind::request_with_budget`.
+const BUDGET_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// A state manager for a single asynchronous operation, designed for use with `egui`.
+///
+/// `Bind` tracks the lifecycle of a `Future` and stores its `Result<T, E>`. It acts as a
+/// bridge between the immediate-mode UI and the background async task, ensuring the UI
+/// can react to changes in state (e.g., show a spinner while `Pending`, display the
+/// result when `Finished`, or show an error).
+#[allow(clippy::struct_excessive_bools)]
+pub struct Bind<T, E> {
+    /// The `egui` time of the most recent frame where this `Bind` was polled.
+    drawn_time_last: f64,
+    /// The `egui` time of the second most recent frame where this `Bind` was polled.
+    drawn_time_prev: f64,
+    /// The `FRAME_INDEX` of the most recent frame where this `Bind` was polled.
+    drawn_on_frame: u64,
+    /// The `FRAME_INDEX` of the second most recent frame where this `Bind` was polled.
+    drawn_on_frame_prev: u64,
+
+    /// The result of the completed async operation. `None` if the task is not `Finished`.
+    pub(crate) data: Option<Result<T, E>>,
+    /// The receiving end of a one-shot channel used to get the result from the background task.
+    /// This is `Some` only when the state is `Pending`.
+    recv: Option<oneshot::Receiver<Completion<T, E>>>,
+
+    /// The current execution state of the async operation.
+    pub(crate) state: State,
+    /// The `egui` time when `state` last changed to a different value.
+    state_entered_time: f64,
+    /// The `egui` time when the most recent operation was started.
+    last_start_time: f64,
+    /// The `egui` time when the most recent operation was completed.
+    last_complete_time: f64,
+    /// The `FRAME_INDEX` when `state` last changed to a different value.
+    state_entered_frame: u64,
+    /// The `FRAME_INDEX` when the most recent operation was started.
+    started_on_frame: u64,
+    /// The `FRAME_INDEX` when the most recent operation was completed.
+    completed_on_frame: u64,
+
+    /// If `true`, the `data` from a `Finished` state is preserved even if the `Bind` instance
+    /// is not polled for one or more frames. If `false`, the data is cleared.
+    retain: bool,
+
+    /// A counter for how many times an async operation has been started.
+    times_executed: usize,
+    /// A counter for how many operations completed with `Ok`.
+    successes: usize,
+    /// A counter for how many operations completed with `Err`.
+    failures: usize,
+    /// A counter for how many results were received or filled in. Unlike the statistics, it
+    /// survives `reset`, so `tee` can tell every new result apart.
+    completions: u64,
+
+    /// `true` if the most recent automatic request was skipped because the app was offline.
+    offline: bool,
+
+    /// If `true`, an error from the in-flight request keeps any existing `Ok` data in place.
+    stale_fallback: bool,
+    /// `true` if the data being presented is a stale value kept after a failed refresh.
+    refresh_failed: bool,
+    /// `true` if the data is a provisional value set by `optimistic_fill`, not yet confirmed
+    /// by the in-flight operation.
+    optimistic: bool,
+
+    /// Timings of the most recently completed operations, oldest first.
+    history: VecDeque<RequestTiming>,
+    /// The durations of the operations in `history`, in the same order, kept in one slice
+    /// for `latency_history`.
+    latencies: Vec<f64>,
+    /// The maximum number of entries kept in `history`. `0` disables recording.
+    history_capacity: usize,
+
+    /// A request queued by `request_paced`, waiting for its slot.
+    paced: Option<PacedRequest<T, E>>,
+    /// The trailing request of a burst of `request_debounced` calls, waiting for its window.
+    debounced: Option<DebouncedRequest<T, E>>,
+
+    /// The index of the source that succeeded in the latest `request_fallback` chain, written
+    /// by the task. `usize::MAX` while no source has succeeded.
+    fallback_source: Option<Arc<AtomicUsize>>,
+
+    /// The minimum time, in seconds, an error stays visible before an automatic refetch.
+    min_error_display: f64,
+
+    /// The validator token (e.g. `ETag`) of the current data, used by `request_conditional`.
+    validator_token: Option<String>,
+
+    /// The completion counter shared with subscriptions. Created by the first `subscribe`.
+    generation: Option<Arc<AtomicU64>>,
+
+    /// A live source (e.g. `request_sse`) whose values replace the data as they arrive.
+    live: Option<LiveFeed<T, E>>,
+
+    /// How long, in seconds, a finished result is kept before returning to `Idle`.
+    auto_clear: Option<f64>,
+
+    /// How values are delivered by live sources started after it is set.
+    backpressure: BackpressureMode,
+
+    /// Aborts the in-flight task started by `request`, if it is still running.
+    abort: Option<oneshot::Sender<()>>,
+    /// Whether `clear` (and so `refresh`) aborts the in-flight task.
+    cancel_on_clear: bool,
+
+    /// How long a task started by `request` may run before it is dropped.
+    default_timeout: Option<Duration>,
+    /// `true` if the latest operation was dropped for exceeding `default_timeout`.
+    timed_out: bool,
+    /// The minimum time, in seconds, an operation stays `Pending`.
+    min_pending: f64,
+    /// Dropped when the current operation leaves `Pending`, which resolves the futures
+    /// returned by `completion_future`.
+    completion_tx: Option<watch::Sender<()>>,
+    /// Why the latest operation ended without a result, until taken by `try_poll`.
+    poll_error: Option<PollError>,
+
+    /// The runtime tasks are spawned on, instead of the global one.
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+    runtime: Option<tokio::runtime::Handle>,
+
+    /// The context set with `with_ctx`, whose clock and repaints this `Bind` uses.
+    #[cfg(feature = "egui")]
+    scope: Option<CtxScope>,
+
+    /// The user-defined key of the pending operation, set by `request_keyed`.
+    operation_key: Option<String>,
+
+    /// The `egui` time at which the budget of `request_with_budget` runs out.
+    budget_deadline: Option<f64>,
+    /// The `egui` time at which the pending operation is dropped by `default_timeout`.
+    timeout_deadline: Option<f64>,
+
+    /// The attempt currently running in `request_retry`, written by the task.
+    attempt: Option<Arc<AtomicUsize>>,
+
+    /// Receives the outcome of the background sync started by `request_local_first`.
+    sync_recv: Option<oneshot::Receiver<Result<(), E>>>,
+    /// The status of the latest `request_local_first` sync.
+    sync_status: Option<SyncStatus>,
+    /// The error of the latest failed `request_local_first` sync.
+    sync_error: Option<E>,
+
+    /// The hash of the dependencies passed to the latest `request_deps`.
+    deps_hash: Option<u64>,
+
+    /// The key passed to the latest `request_for_key`, type-erased.
+    last_key: Option<Box<dyn Any + Send + Sync>>,
+
+    /// The `egui` time of the latest call to `request_debounced`.
+    debounce_last: f64,
+
+    /// Receives progress reported by the task started by `request_with_progress`.
+    progress_recv: Option<mpsc::UnboundedReceiver<ProgressValue>>,
+    /// The latest progress reported by the current operation.
+    progress: Option<ProgressValue>,
+
+    /// A name identifying this `Bind` in logs and to the mock handler.
+    name: Option<String>,
+
+    /// A canned result from the mock handler, waiting to be delivered.
+    #[cfg(feature = "test-util")]
+    mock: Option<MockPending<T, E>>,
+}
+
+impl<T, E> Debug for Bind<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = f.debug_struct("Bind");
+        let mut out = out
+            .field("state", &self.state)
+            .field("state_entered_time", &self.state_entered_time)
+            .field("retain", &self.retain)
+            .field("drawn_time_last", &self.drawn_time_last)
+            .field("drawn_time_prev", &self.drawn_time_prev)
+            .field("drawn_on_frame", &self.drawn_on_frame)
+            .field("drawn_on_frame_prev", &self.drawn_on_frame_prev)
+            .field("last_start_time", &self.last_start_time)
+            .field("last_complete_time", &self.last_complete_time)
+            .field("state_entered_frame", &self.state_entered_frame)
+            .field("started_on_frame", &self.started_on_frame)
+            .field("completed_on_frame", &self.completed_on_frame)
+            .field("times_executed", &self.times_executed)
+            .field("completions", &self.completions)
+            .field("successes", &self.successes)
+            .field("failures", &self.failures)
+            .field("offline", &self.offline)
+            .field("stale_fallback", &self.stale_fallback)
+            .field("refresh_failed", &self.refresh_failed)
+            .field("optimistic", &self.optimistic)
+            .field("history", &self.history)
+            .field("latencies", &self.latencies)
+            .field("history_capacity", &self.history_capacity)
+            .field("paced_slot", &self.paced.as_ref().map(|paced| paced.slot))
+            .field("debounced_at", &self.debounced.as_ref().map(|d| d.start_at))
+            .field(
+                "fallback_source",
+                &self
+                    .fallback_source
+                    .as_ref()
+                    .map(|index| index.load(Ordering::Relaxed)),
+            )
+            .field("min_error_display", &self.min_error_display)
+            .field("auto_clear", &self.auto_clear)
+            .field("backpressure", &self.backpressure)
+            .field("abort", &self.abort.is_some())
+            .field("cancel_on_clear", &self.cancel_on_clear)
+            .field("default_timeout", &self.default_timeout)
+            .field("timed_out", &self.timed_out)
+            .field("min_pending", &self.min_pending)
+            .field("completion_tx", &self.completion_tx.is_some())
+            .field("poll_error", &self.poll_error)
+            .field("operation_key", &self.operation_key)
+            .field("budget_deadline", &self.budget_deadline)
+            .field("timeout_deadline", &self.timeout_deadline)
+            .field(
+                "attempt",
+                &self.attempt.as_ref().map(|a| a.load(Ordering::Relaxed)),
+            )
+            .field("sync_recv", &self.sync_recv.is_some())
+            .field("sync_status", &self.sync_status)
+            .field("sync_error", &self.sync_error.is_some())
+            .field("deps_hash", &self.deps_hash)
+            .field("last_key", &self.last_key.is_some())
+            .field("debounce_last", &self.debounce_last)
+            .field("progress_recv", &self.progress_recv.is_some())
+            .field("progress", &self.progress.is_some())
+            .field("name", &self.name)
+            .field("validator_token", &self.validator_token)
+            .field(
+                "generation",
+                &self
+                    .generation
+                    .as_ref()
+                    .map(|generation| generation.load(Ordering::Relaxed)),
+            )
+            .field(
+                "live",
+                &self
+                    .live
+                    .as_ref()
+                    .map(|live| ConnectionStatus::from_u8(live.status.load(Ordering::Relaxed))),
+            );
+
+        #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+        {
+            out = out.field("runtime", &self.runtime.is_some());
+        }
+
+        #[cfg(feature = "egui")]
+        {
+            out = out.field("scope", &self.scope.is_some());
+        }
+
+        #[cfg(feature = "test-util")]
+        {
+            out = out.field(
+                "mock_ready_at",
+                &self.mock.as_ref().map(|mock| mock.ready_at),
+            );
+        }
+
+        // Avoid printing the full data/recv content for cleaner debug output.
+        let placeholder = |some: bool| if some { "Some(...)" } else { "None" };
+        out = out.field("data", &placeholder(self.data.is_some()));
+        out = out.field("recv", &placeholder(self.recv.is_some()));
+
+        out.finish()
+    }
+}
+
+impl<T: std::fmt::Display, E: std::fmt::Display> std::fmt::Display for Bind<T, E> {
+    /// Formats the current state for logs and simple labels: `Idle`, `Loading…`, the value, or
+    /// `Error: …`.
+    ///
+    /// Since `fmt` only has `&self`, this does not `poll`; it reflects the state as of the last
+    /// poll.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.state, &self.data) {
+            (State::Pending, _) => f.write_str("Loading…"),
+            (State::Finished, Some(Ok(data))) => data.fmt(f),
+            (State::Finished, Some(Err(err))) => write!(f, "Error: {err}"),
+            (State::Idle | State::Finished, _) => f.write_str("Idle"),
+        }
+    }
+}
+
+/// Serializes the success data only, as an `Option<T>`.
+///
+/// Errors, the in-flight operation and all settings are skipped, so the last value can be
+/// persisted (e.g. in `eframe::App::save`) and shown immediately on the next start.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, E> serde::Serialize for Bind<T, E> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Data kept from an earlier operation while a refresh is pending is saved as well.
+        self.data
+            .as_ref()
+            .and_then(|data| data.as_ref().ok())
+            .serialize(serializer)
+    }
+}
+
+/// Deserializes a `Bind` saved by its `Serialize` implementation.
+///
+/// Saved data is loaded as if passed to `fill`: the `Bind` is `Finished`, with the completion
+/// time set to the current frame. Otherwise, it is `Idle`. Either way it uses the defaults of
+/// `Bind::default`, so call `set_retain` afterwards if the data should survive frames in
+/// which it is not drawn.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + 'static, E: 'static> serde::Deserialize<'de> for Bind<T, E> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut bind = Self::default();
+        if let Some(data) = Option::<T>::deserialize(deserializer)? {
+            bind.fill(Ok(data));
+        }
+        Ok(bind)
+    }
+}
+
+impl<T: 'static, E: 'static> Default for Bind<T, E> {
+    /// Creates a default `Bind` instance in an `Idle` state.
+    ///
+    /// The `retain` flag is set to `false`. This implementation does not require `T` or `E`
+    /// to implement `Default`.
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// Aborts the in-flight task when the `Bind` is dropped, as `Bind::cancel` would.
+///
+/// Without the `cancel-on-drop` feature, a dropped `Bind`'s task runs to completion and its
+/// result is discarded.
+#[cfg(feature = "cancel-on-drop")]
+impl<T, E> Drop for Bind<T, E> {
+    fn drop(&mut self) {
+        if let Some(abort) = self.abort.take() {
+            // The task may already be done, in which case there is nothing to abort.
+            let _ = abort.send(());
+        }
+    }
+}
+
+/// Builds a `Bind` with options beyond the `retain` flag of `Bind::new`.
+///
+/// Created by `Bind::builder`.
+///
+/// # Example
+/// ```ignore
+/// let bind = Bind::<Report, String>::builder()
+///     .retain(true)
+///     .default_timeout(Duration::from_secs(10))
+///     .cancel_on_refresh(true)
+///     .build();
+/// ```
+pub struct BindBuilder<T, E> {
+    bind: Bind<T, E>,
+}
+
+impl<T, E> Debug for BindBuilder<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BindBuilder")
+            .field("bind", &self.bind)
+            .finish()
+    }
+}
+
+impl<T: 'static, E: 'static> BindBuilder<T, E> {
+    /// Sets whether data is kept when the `Bind` is not drawn for a frame. See `Bind::new`.
+    #[must_use]
+    pub const fn retain(mut self, retain: bool) -> Self {
+        self.bind.retain = retain;
+        self
+    }
+
+    /// Spawns the `Bind`'s tasks on `handle` instead of the global runtime (see
+    /// `init_runtime`). Requires the `runtime-tokio` feature.
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+    #[must_use]
+    pub fn runtime(mut self, handle: tokio::runtime::Handle) -> Self {
+        self.bind.runtime = Some(handle);
+        self
+    }
+
+    /// Attaches the `Bind` to `ctx`. See `Bind::with_ctx`.
+    #[cfg(feature = "egui")]
+    #[must_use]
+    pub fn context(mut self, ctx: &egui::Context) -> Self {
+        self.bind.attach_ctx(ctx);
+        self
+    }
+
+    /// Drops operations started by `request` (and the methods built on it) that run longer
+    /// than `timeout`. See `Bind::timed_out`.
+    #[must_use]
+    pub const fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.bind.default_timeout = Some(timeout);
+        self
+    }
+
+    /// Keeps the timings of the last `capacity` completed operations. See
+    /// `Bind::set_history_capacity`.
+    #[must_use]
+    pub const fn history_capacity(mut self, capacity: usize) -> Self {
+        self.bind.history_capacity = capacity;
+        self
+    }
+
+    /// Keeps every operation `Pending` for at least `duration`, even if it finishes sooner.
+    ///
+    /// A request answered from a cache within a frame or two otherwise flashes its spinner
+    /// for a moment, which looks like a glitch. The result is held back until `duration` has
+    /// passed since the start of the operation, and a repaint is requested for that moment.
+    #[must_use]
+    pub const fn min_pending_duration(mut self, duration: Duration) -> Self {
+        self.bind.min_pending = duration.as_secs_f64();
+        self
+    }
+
+    /// Sets whether `refresh` (and `clear`) cancels an in-flight operation. See
+    /// `Bind::set_cancel_on_clear`.
+    #[must_use]
+    pub const fn cancel_on_refresh(mut self, cancel: bool) -> Self {
+        self.bind.cancel_on_clear = cancel;
+        self
+    }
+
+    /// Creates the `Bind`.
+    #[must_use]
+    pub fn build(self) -> Bind<T, E> {
+        self.bind
+    }
+}
+
+/// A trait alias for `Send` on native targets.
+///
+/// On WASM, this trait has no bounds, allowing non-`Send` types to be used in `Bind`
+/// since WASM is single-threaded.
+#[cfg(not(target_family = "wasm"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_family = "wasm"))]
+impl<T: Send> MaybeSend for T {}
+
+/// A trait alias with no bounds on WASM targets.
+///
+/// This allows `Bind` to work with `!Send` futures and data types in a single-threaded
+/// web environment.
+#[cfg(target_family = "wasm")]
+pub trait MaybeSend {}
+#[cfg(target_family = "wasm")]
+impl<T> MaybeSend for T {}
+
+/// A boxed, type-erased task ready to be spawned.
+#[cfg(not(target_family = "wasm"))]
+type BoxTask = Pin<Box<dyn Future<Output = ()> + Send>>;
+/// A boxed, type-erased task ready to be spawned.
+#[cfg(target_family = "wasm")]
+type BoxTask = Pin<Box<dyn Future<Output = ()>>>;
+
+/// A request answered by the mock handler, waiting for its delivery time.
+#[cfg(feature = "test-util")]
+struct MockPending<T, E> {
+    /// The frame-clock time at which the result is delivered.
+    ready_at: f64,
+    /// The channel `recv` is listening on.
+    tx: oneshot::Sender<Completion<T, E>>,
+    /// The canned result.
+    result: Result<T, E>,
+}
+
+/// A request waiting for its slot from a `Pacer`.
+struct PacedRequest<T, E> {
+    /// The `egui` time at which the request may start.
+    slot: f64,
+    /// The pacer's interval, used to derive the queue position.
+    interval: f64,
+    /// The task to spawn. The `Mutex` only keeps `Bind` `Sync`; it is always accessed
+    /// through `&mut` or by value, so it never blocks.
+    task: Mutex<BoxTask>,
+    /// The receiver that becomes the `Bind`'s receiver once the task is spawned.
+    recv: oneshot::Receiver<Completion<T, E>>,
+}
+
+/// The trailing request of a burst of `Bind::request_debounced` calls.
+struct DebouncedRequest<T, E> {
+    /// The `egui` time at which the request starts.
+    start_at: f64,
+    /// The abortable task to spawn. The `Mutex` only keeps `Bind` `Sync`, as in
+    /// `PacedRequest`.
+    task: Mutex<BoxTask>,
+    /// The receiver that becomes the `Bind`'s receiver once the task is spawned.
+    recv: oneshot::Receiver<Completion<T, E>>,
+    /// Aborts the task once it is spawned.
+    abort: oneshot::Sender<()>,
+}
+
+/// Spawns a task onto the appropriate runtime (the one selected by the `runtime-*` feature for
+/// native, `wasm-bindgen-futures` for WASM).
+pub(crate) fn spawn<F>(fut: F)
+where
+    F: Future<Output = ()> + MaybeSend + 'static,
+{
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+    if let Some(handle) = RUNTIME_HANDLE.get() {
+        handle.spawn(fut);
+    } else {
+        ASYNC_RUNTIME.spawn(fut);
+    }
+
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-async-std"))]
+    async_std::task::spawn(fut);
+
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-smol"))]
+    smol::spawn(fut).detach();
+
+    #[cfg(target_family = "wasm")]
+    wasm_bindgen_futures::spawn_local(fut);
+}
+
+/// The requests of one key type that share a hash, each with the `oneshot::Sender`s waiting
+/// on it. Type-erased, since the registry holds every key and result type.
+type DedupBucket = Box<dyn Any + Send>;
+
+/// Deduplicated requests that are currently in flight, keyed by the `TypeId` of the key and
+/// `Result` types, so that equal keys with different types never collide, and by the hash of
+/// the key. Keys with the same hash are told apart by equality within their bucket.
+static IN_FLIGHT: LazyLock<Mutex<HashMap<(TypeId, u64), DedupBucket>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The concrete type behind a `DedupBucket`.
+type Bucket<K, T, E> = Vec<(K, Vec<oneshot::Sender<Completion<T, E>>>)>;
+
+/// The registration of a coalesced request in `IN_FLIGHT`, owned by its shared task.
+///
+/// The key is removed when the guard is dropped, so it is freed on every exit path of the
+/// task, including a panic, an abort or a runtime shutdown. Dropping the waiting senders then
+/// lets every attached `Bind` leave `Pending`.
+struct InFlightGuard<K: Eq + 'static, T: 'static, E: 'static> {
+    slot: (TypeId, u64),
+    /// `None` once the waiters have been taken.
+    key: Option<K>,
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl<K: Eq + 'static, T: 'static, E: 'static> InFlightGuard<K, T, E> {
+    /// Resolves once no `Bind` waits for the result any more, e.g. because all of them were
+    /// cancelled or dropped.
+    ///
+    /// Takes `&mut self` so that the task polling it is `Send` without requiring `K: Sync`.
+    fn poll_abandoned(&mut self, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(PoisonError::into_inner);
+        let waiters = in_flight
+            .get_mut(&self.slot)
+            .and_then(|bucket| bucket.downcast_mut::<Bucket<K, T, E>>())
+            .zip(self.key.as_ref())
+            .and_then(|(bucket, key)| bucket.iter_mut().find(|(waiting, _)| waiting == key));
+
+        let abandoned = waiters.is_none_or(|(_, waiters)| {
+            waiters.iter_mut().all(|tx| tx.poll_canceled(cx).is_ready())
+        });
+        if abandoned {
+            std::task::Poll::Ready(())
+        } else {
+            std::task::Poll::Pending
+        }
+    }
+
+    /// Removes the key from `IN_FLIGHT` and returns the senders waiting on it.
+    fn take_waiters(&mut self) -> Vec<oneshot::Sender<Completion<T, E>>> {
+        let Some(key) = self.key.take() else {
+            return Vec::new();
+        };
+
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(bucket) = in_flight
+            .get_mut(&self.slot)
+            .and_then(|bucket| bucket.downcast_mut::<Bucket<K, T, E>>())
+        else {
+            return Vec::new();
+        };
+        let waiters = bucket
+            .iter()
+            .position(|(waiting, _)| *waiting == key)
+            .map(|index| bucket.swap_remove(index).1)
+            .unwrap_or_default();
+        if bucket.is_empty() {
+            in_flight.remove(&self.slot);
+        }
+        waiters
+    }
+}
+
+impl<K: Eq + 'static, T: 'static, E: 'static> Drop for InFlightGuard<K, T, E> {
+    fn drop(&mut self) {
+        drop(self.take_waiters());
+    }
+}
+
+/// Awaits `fut`, returning `None` if `duration` elapses first.
+///
+/// The future is only borrowed, so it can still be polled again after a timeout.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+async fn timeout<F: Future + Unpin>(duration: Duration, fut: F) -> Option<F::Output> {
+    tokio::time::timeout(duration, fut).await.ok()
+}
+
+/// Awaits `fut`, returning `None` if `duration` elapses first.
+///
+/// The future is only borrowed, so it can still be polled again after a timeout.
+#[cfg(not(all(not(target_family = "wasm"), feature = "runtime-tokio")))]
+async fn timeout<F: Future + Unpin>(duration: Duration, mut fut: F) -> Option<F::Output> {
+    use std::task::Poll;
+
+    let mut timer = std::pin::pin!(sleep(duration));
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = Pin::new(&mut fut).poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        timer.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
+
+/// Converts a `Completion` to new types with `f`, which is called at most once.
+fn map_completion<T, E, U, E2>(
+    completion: Completion<T, E>,
+    f: impl FnOnce(Result<T, E>) -> Result<U, E2>,
+) -> Completion<U, E2> {
+    match completion {
+        Completion::Result(result) => Completion::Result(f(result)),
+        Completion::Conditional(Err(err)) => Completion::Result(f(Err(err))),
+        Completion::Conditional(Ok(CondResult::Modified(data, token))) => match f(Ok(data)) {
+            Ok(data) => Completion::Conditional(Ok(CondResult::Modified(data, token))),
+            Err(err) => Completion::Conditional(Err(err)),
+        },
+        Completion::Conditional(Ok(CondResult::NotModified)) => {
+            Completion::Conditional(Ok(CondResult::NotModified))
+        }
+        Completion::TimedOut => Completion::TimedOut,
+    }
+}
+
+/// Waits for `duration` without blocking the runtime.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Waits for `duration` without blocking the runtime.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-async-std"))]
+async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+/// Waits for `duration` without blocking the runtime.
+#[cfg(all(not(target_family = "wasm"), feature = "runtime-smol"))]
+async fn sleep(duration: Duration) {
+    smol::Timer::after(duration).await;
+}
+
+/// Waits for `duration` without blocking the browser.
+#[cfg(target_family = "wasm")]
+async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Awaits `fut` unless a value is sent through `abort` first, in which case `fut` is dropped.
+///
+/// Dropping the sender without sending does not abort the task.
+async fn abortable<F: Future<Output = ()>>(fut: F, abort: oneshot::Receiver<()>) {
+    use std::task::Poll;
+
+    let mut fut = std::pin::pin!(fut);
+    let mut abort = Some(abort);
+    std::future::poll_fn(|cx| {
+        if let Some(rx) = abort.as_mut() {
+            match Pin::new(rx).poll(cx) {
+                Poll::Ready(Ok(())) => return Poll::Ready(()),
+                Poll::Ready(Err(_)) => abort = None,
+                Poll::Pending => {}
+            }
+        }
+        fut.as_mut().poll(cx)
+    })
+    .await;
+}
+
+/// Awaits `fut`, returning the payload of a panic raised while polling it as `Err`.
+///
+/// On targets that abort on panic (such as `wasm32-unknown-unknown` by default), a panic still
+/// aborts and nothing is caught.
+async fn catch_unwind<F: Future>(fut: F) -> Result<F::Output, Box<dyn Any + Send>> {
+    use std::{panic::AssertUnwindSafe, task::Poll};
+
+    let mut fut = std::pin::pin!(fut);
+    std::future::poll_fn(|cx| {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| fut.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    })
+    .await
+}
+
+impl<T: 'static, E: 'static> Bind<T, E> {
+    /// Creates a new `Bind` instance with a specific retain policy.
+    ///
+    /// # Parameters
+    /// - `retain`: If `true`, the result of the operation is kept even if the `Bind`
+    ///   is not polled in a frame. If `false`, the result is cleared if not polled
+    ///   for one frame, returning the `Bind` to an `Idle` state.
+    #[must_use]
+    pub const fn new(retain: bool) -> Self {
+        Self {
+            drawn_time_last: 0.0,
+            drawn_time_prev: 0.0,
+            drawn_on_frame: 0,
+            drawn_on_frame_prev: 0,
+            data: None,
+            recv: None,
+            state: State::Idle,
+            state_entered_time: f64::MIN,
+            last_start_time: 0.0,
+            last_complete_time: f64::MIN, // Set to a very low value to ensure `since_completed` is large initially.
+            state_entered_frame: u64::MAX,
+            started_on_frame: u64::MAX,
+            completed_on_frame: u64::MAX,
+            retain,
+            times_executed: 0,
+            completions: 0,
+            successes: 0,
+            failures: 0,
+            offline: false,
+            stale_fallback: false,
+            refresh_failed: false,
+            optimistic: false,
+            history: VecDeque::new(),
+            latencies: Vec::new(),
+            history_capacity: 0,
+            paced: None,
+            debounced: None,
+            fallback_source: None,
+            min_error_display: 0.0,
+            validator_token: None,
+            generation: None,
+            live: None,
+            auto_clear: None,
+            backpressure: BackpressureMode::KeepLatest,
+            abort: None,
+            cancel_on_clear: false,
+            default_timeout: None,
+            timed_out: false,
+            min_pending: 0.0,
+            completion_tx: None,
+            poll_error: None,
+            #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+            runtime: None,
+            #[cfg(feature = "egui")]
+            scope: None,
+            operation_key: None,
+            budget_deadline: None,
+            timeout_deadline: None,
+            attempt: None,
+            sync_recv: None,
+            sync_status: None,
+            sync_error: None,
+            deps_hash: None,
+            last_key: None,
+            debounce_last: f64::NEG_INFINITY,
+            progress_recv: None,
+            progress: None,
+            name: None,
+            #[cfg(feature = "test-util")]
+            mock: None,
+        }
+    }
+
+    /// Returns a `BindBuilder` for configuring a new `Bind`, starting from `Bind::default`.
+    #[must_use]
+    pub const fn builder() -> BindBuilder<T, E> {
+        BindBuilder {
+            bind: Self::new(false),
+        }
+    }
+
+    /// Internal helper to prepare the state and communication channel for a new async request.
+    #[allow(clippy::type_complexity)]
+    fn prepare_channel(
+        &mut self,
+    ) -> (
+        oneshot::Sender<Completion<T, E>>,
+        oneshot::Receiver<Completion<T, E>>,
+    ) {
+        self.poll(); // Ensure state is up-to-date before starting.
+        self.begin_request();
+
+        // An explicit request supersedes one still waiting on a pacer or a debounce window.
+        self.paced = None;
+        self.debounced = None;
+
+        oneshot::channel()
+    }
+
+    /// Internal helper that records the start of a new operation and moves to `Pending`.
+    fn begin_request(&mut self) {
+        self.last_start_time = self.now();
+        self.started_on_frame = self.frame_index();
+        self.set_state(State::Pending);
+        self.completion_tx = Some(watch::channel(()).0);
+
+        self.times_executed += 1;
+        self.offline = false;
+        self.stale_fallback = false;
+        self.fallback_source = None;
+        self.live = None;
+        self.abort = None;
+        self.operation_key = None;
+        self.budget_deadline = None;
+        self.timeout_deadline = None;
+        self.attempt = None;
+        self.timed_out = false;
+        self.poll_error = None;
+        self.progress_recv = None;
+        self.progress = None;
+
+        #[cfg(feature = "test-util")]
+        {
+            self.mock = None;
+        }
+    }
+
+    /// Internal helper that spawns a task on the runtime set with `BindBuilder::runtime`, or as
+    /// by `spawn` if there is none.
+    #[cfg_attr(not(feature = "runtime-tokio"), allow(clippy::unused_self))]
+    fn spawn_task<F>(&self, fut: F)
+    where
+        F: Future<Output = ()> + MaybeSend + 'static,
+    {
+        #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+        if let Some(handle) = &self.runtime {
+            handle.spawn(fut);
+            return;
+        }
+
+        spawn(fut);
+    }
+
+    /// Attaches the `Bind` to `ctx`, so it follows that context's frame clock and its tasks
+    /// repaint that context. See `with_ctx`.
+    #[cfg(feature = "egui")]
+    pub(crate) fn attach_ctx(&mut self, ctx: &egui::Context) {
+        if self.scope.as_ref().is_none_or(|scope| scope.ctx != *ctx) {
+            self.scope = Some(CtxScope::new(ctx));
+        }
+    }
+
+    /// Returns the context repaints should be requested from: the attached one, or `CTX`.
+    #[cfg(feature = "egui")]
+    pub(crate) fn ctx(&self) -> Option<&egui::Context> {
+        self.scope
+            .as_ref()
+            .map(|scope| &scope.ctx)
+            .or_else(|| CTX.get())
+    }
+
+    /// Returns where this `Bind`'s tasks should request repaints.
+    fn repainter(&self) -> Repainter {
+        Repainter {
+            #[cfg(feature = "egui")]
+            ctx: self.scope.as_ref().map(|scope| scope.ctx.clone()),
+        }
+    }
+
+    /// Returns the current time on the attached context's clock, or else as by `now`.
+    pub(crate) fn now(&self) -> f64 {
+        #[cfg(feature = "egui")]
+        if let Some(clock) = self.scope.as_ref().and_then(CtxScope::driven_clock) {
+            return clock.now();
+        }
+        now()
+    }
+
+    /// Returns the time of the previous frame on the attached context's clock, or else the
+    /// global `LAST_FRAME`.
+    fn last_frame(&self) -> f64 {
+        #[cfg(feature = "egui")]
+        if let Some(clock) = self.scope.as_ref().and_then(CtxScope::driven_clock) {
+            return clock.last_frame();
+        }
+        LAST_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Returns the index of the current frame on the attached context's clock, or else the
+    /// global `FRAME_INDEX`.
+    fn frame_index(&self) -> u64 {
+        #[cfg(feature = "egui")]
+        if let Some(clock) = self.scope.as_ref().and_then(CtxScope::driven_clock) {
+            return clock.frame_index();
+        }
+        frame_index()
+    }
+
+    /// Reads the frame clock this `Bind` follows: the attached context's, or the global one.
+    fn frame_time(&self) -> FrameTime {
+        #[cfg(feature = "egui")]
+        if let Some(clock) = self.scope.as_ref().and_then(CtxScope::driven_clock) {
+            return FrameTime {
+                now: clock.now(),
+                index: clock.frame_index(),
+            };
+        }
+        FrameTime::global()
+    }
+
+    /// Internal async function that awaits the user's future and sends the result back.
+    ///
+    /// The future runs inside an `egui_async::request` span, and its duration and outcome are
+    /// logged at the `debug` level once it completes.
+    ///
+    /// If `limit` is set and the future runs longer, it is dropped and `Completion::TimedOut` is
+    /// sent instead. Otherwise its result is sent wrapped by `wrap`, e.g. `Completion::Result`.
+    async fn req_inner<R, F>(
+        fut: F,
+        wrap: fn(Result<R, E>) -> Completion<T, E>,
+        tx: oneshot::Sender<Completion<T, E>>,
+        times_executed: usize,
+        limit: Option<Duration>,
+        repaint: Repainter,
+    ) where
+        F: Future<Output = Result<R, E>> + 'static,
+        T: MaybeSend,
+    {
+        let span = tracing::debug_span!("egui_async::request", times_executed);
+        let result = async move {
+            #[cfg(not(target_family = "wasm"))]
+            let start = std::time::Instant::now();
+            // `Instant` is unavailable in browsers, so use the page's clock there.
+            #[cfg(target_family = "wasm")]
+            let start = performance_now();
+
+            let result = fut.await;
+
+            #[cfg(not(target_family = "wasm"))]
+            let elapsed = start.elapsed();
+            #[cfg(target_family = "wasm")]
+            let elapsed = performance_now()
+                .zip(start)
+                .map(|(end, start)| Duration::from_secs_f64((end - start).max(0.0)))
+                .unwrap_or_default();
+
+            let outcome = if result.is_ok() { "ok" } else { "error" };
+            debug!(?elapsed, outcome, "Request completed.");
+            result
+        }
+        .instrument(span);
+
+        let completion = match limit {
+            Some(limit) => timeout(limit, Box::pin(result))
+                .await
+                .map_or(Completion::TimedOut, wrap),
+            None => wrap(result.await),
+        };
+
+        if matches!(tx.send(completion), Ok(())) {
+            // If the send was successful, request a repaint to show the new data.
+            repaint.request();
+        } else {
+            // This occurs if the `Bind` was dropped before the future completed.
+            warn!("Future result was dropped because the receiver was gone.");
+        }
+    }
+
+    /// Starts an asynchronous operation if the `Bind` is not already `Pending`.
+    ///
+    /// The provided future `f` is spawned onto the appropriate runtime (the one selected by the
+    /// `runtime-*` feature for native, `wasm-bindgen-futures` for WASM). The `Bind` state transitions to `Pending`.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request<Fut>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        #[cfg(feature = "test-util")]
+        if self.request_mocked() {
+            return;
+        }
+
+        let task = self.start_request(f, Completion::Result);
+        self.spawn_task(task);
+    }
+
+    /// Like `request`, but spawns the future on `handle` instead of the `Bind`'s runtime.
+    ///
+    /// This routes individual requests to a specific runtime, e.g. when an app keeps separate
+    /// runtimes for I/O and compute. For a `Bind` that should always use the same runtime, see
+    /// `BindBuilder::runtime`. Requires the `runtime-tokio` feature, and is not available on
+    /// WASM.
+    ///
+    /// This method calls `poll()` internally.
+    #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+    pub fn request_on<Fut>(&mut self, handle: &tokio::runtime::Handle, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        #[cfg(feature = "test-util")]
+        if self.request_mocked() {
+            return;
+        }
+
+        let task = self.start_request(f, Completion::Result);
+        handle.spawn(task);
+    }
+
+    /// Internal helper for `request`, `request_on` and `request_conditional` that starts a new
+    /// operation and returns the abortable task to spawn for it. The result of `f` is sent to
+    /// the `Bind` wrapped by `wrap`.
+    fn start_request<R, Fut>(
+        &mut self,
+        f: Fut,
+        wrap: fn(Result<R, E>) -> Completion<T, E>,
+    ) -> impl Future<Output = ()> + MaybeSend + 'static
+    where
+        R: 'static,
+        Fut: Future<Output = Result<R, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let (tx, rx) = self.prepare_channel();
+        let (abort_tx, abort_rx) = oneshot::channel();
+
+        self.recv = Some(rx);
+        self.abort = Some(abort_tx);
+        self.arm_timeout();
+        abortable(
+            Self::req_inner(
+                f,
+                wrap,
+                tx,
+                self.times_executed,
+                self.default_timeout,
+                self.repainter(),
+            ),
+            abort_rx,
+        )
+    }
+
+    /// Starts an asynchronous operation built by `f` from owned `args`.
+    ///
+    /// The future must be `'static`, so it cannot borrow app state. This resolves the borrow
+    /// up front: clone (or otherwise take ownership of) what the request needs into `args`,
+    /// and `f` builds the future from it. Plain `async fn`s taking their inputs by value fit
+    /// directly.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// async fn fetch_user(client: reqwest::Client, id: u64) -> Result<User, String> { /* ... */ }
+    ///
+    /// // `self.client` and `self.selected` are only borrowed to build the arguments.
+    /// self.user.request_with_args((self.client.clone(), self.selected), |(client, id)| {
+    ///     fetch_user(client, id)
+    /// });
+    /// ```
+    pub fn request_with_args<A, Fut>(&mut self, args: A, f: impl FnOnce(A) -> Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(f(args));
+    }
+
+    /// Like `request`, but for a future with a different error type, which is converted to
+    /// `E` with `map`.
+    ///
+    /// The conversion runs inside the spawned task, so the `Bind` only ever sees `E`. This
+    /// saves a `.map_err(...)` in every future when several APIs with their own error types
+    /// feed `Bind`s that share one.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// self.user.request_map_err(fetch_user(id), |e: reqwest::Error| e.to_string());
+    /// self.config.request_map_err(load_config(), |e: std::io::Error| e.to_string());
+    /// ```
+    pub fn request_map_err<Fut, E2>(
+        &mut self,
+        f: Fut,
+        map: impl FnOnce(E2) -> E + MaybeSend + 'static,
+    ) where
+        Fut: Future<Output = Result<T, E2>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(async move { f.await.map_err(map) });
+    }
+
+    /// Starts an asynchronous operation like `request`, turning a panic in `f` into an error.
+    ///
+    /// Without this, a panicking future (e.g. an `unwrap` in third-party code) ends its task
+    /// and the `Bind` only reports `PollError::TaskDropped`. Here the panic payload is passed
+    /// to `panic_to_err` instead, and the `Bind` finishes with the returned error. The panic
+    /// hook still runs, so the panic is logged as usual.
+    ///
+    /// Panics can only be caught where they unwind; on `wasm32-unknown-unknown`, which aborts
+    /// on panic by default, this behaves like `request`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// bind.request_catch_unwind(fetch_with_third_party_client(), |payload| {
+    ///     let msg = payload
+    ///         .downcast_ref::<&str>()
+    ///         .map(ToString::to_string)
+    ///         .or_else(|| payload.downcast_ref::<String>().cloned())
+    ///         .unwrap_or_else(|| "unknown panic".to_string());
+    ///     format!("The request panicked: {msg}")
+    /// });
+    /// ```
+    pub fn request_catch_unwind<Fut>(
+        &mut self,
+        f: Fut,
+        panic_to_err: impl FnOnce(Box<dyn Any + Send>) -> E + MaybeSend + 'static,
+    ) where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(async move {
+            catch_unwind(f)
+                .await
+                .unwrap_or_else(|p| Err(panic_to_err(p)))
+        });
+    }
+
+    /// Cancels the in-flight operation, dropping its future at the next await point.
+    ///
+    /// This aborts tasks started by `request` and the methods built on it (`refresh`,
+    /// `read_or_request`, `search_latest`, ...), drops a request queued by `request_paced`,
+    /// and disconnects a live source such as `request_sse`. Tasks started by other means
+    /// (e.g. `request_parallel`) cannot be interrupted, but their results are discarded.
+    ///
+    /// A `Pending` `Bind` goes back to `Finished` if it still holds data from an earlier
+    /// operation, and to `Idle` otherwise.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn cancel(&mut self) {
+        self.poll();
+        self.abort_task();
+        self.paced = None;
+        self.debounced = None;
+        self.live = None;
+
+        #[cfg(feature = "test-util")]
+        {
+            self.mock = None;
+        }
+
+        if matches!(self.state, State::Pending) {
+            self.recv = None;
+            self.settle_after_abort();
+        }
+    }
+
+    /// Sets whether `clear` (and therefore `refresh`) cancels an in-flight operation, as by
+    /// `cancel`, instead of letting it run to completion. Defaults to `false`.
+    ///
+    /// Enable this for expensive operations such as large downloads, where finishing a task
+    /// whose result will be discarded anyway only wastes resources.
+    pub const fn set_cancel_on_clear(&mut self, cancel: bool) {
+        self.cancel_on_clear = cancel;
+    }
+
+    /// Internal helper that aborts the task started by the latest `request`, if still running.
+    fn abort_task(&mut self) {
+        if let Some(abort) = self.abort.take() {
+            // The task may already be done, in which case there is nothing to abort.
+            let _ = abort.send(());
+        }
+    }
+
+    /// Internal helper that leaves `Pending` after the operation ended without a result.
+    fn settle_after_abort(&mut self) {
+        self.discard_optimistic();
+        if self.data.is_some() {
+            self.set_state(State::Finished);
+        } else {
+            self.set_state(State::Idle);
+        }
+    }
+
+    /// Internal helper that drops a provisional value set by `optimistic_fill`, since the
+    /// operation that was meant to confirm it ended without a result of its own.
+    fn discard_optimistic(&mut self) {
+        if self.optimistic {
+            self.data = None;
+            self.optimistic = false;
+        }
+    }
+
+    /// Internal helper that answers a request from the mock handler, if it has a response.
+    /// Returns `true` if the request was mocked.
+    #[cfg(feature = "test-util")]
+    fn request_mocked(&mut self) -> bool {
+        let request = crate::mock::MockRequest {
+            name: self.name.as_deref(),
+            type_name: std::any::type_name::<Result<T, E>>(),
+        };
+        let Some(response) = crate::mock::respond(&request) else {
+            return false;
+        };
+        let Some((result, delay)) = response.into_result::<T, E>() else {
+            warn!(
+                "Mock response for {:?} does not match {}; running the real request.",
+                request.name, request.type_name
+            );
+            return false;
+        };
+
+        let (tx, rx) = self.prepare_channel();
+        self.recv = Some(rx);
+        self.mock = Some(MockPending {
+            ready_at: self.last_start_time + delay,
+            tx,
+            result,
+        });
+        true
+    }
+
+    /// Sets a name identifying this `Bind`, e.g. for the `test-util` mock handler.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = Some(name.into());
+    }
+
+    /// Returns the name set with `set_name`, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Starts an operation identified by a user-defined key, so it can be resumed after a
+    /// reload with `resume_pending`.
+    ///
+    /// While the operation is `Pending`, `pending_operation` returns `key`. Persist it together
+    /// with the rest of your app state (e.g. in `eframe::App::save`).
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_keyed<Fut>(&mut self, key: impl Into<String>, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(f);
+        self.operation_key = Some(key.into());
+    }
+
+    /// Returns the key of the operation started by `request_keyed`, while it is `Pending`.
+    pub fn pending_operation(&self) -> Option<&str> {
+        self.operation_key.as_deref()
+    }
+
+    /// Re-issues an operation that was pending when the app was last saved.
+    ///
+    /// If `key` is `Some` (as previously returned by `pending_operation`), the request is
+    /// started again with the future built by `rehydrate`, and keyed the same way. Nothing
+    /// happens for `None`. Returns `true` if a request was started.
+    ///
+    /// This only re-issues the operation from scratch; the original task cannot be recovered,
+    /// so any progress it had made is lost.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // On startup, with `saved_key: Option<String>` restored from storage:
+    /// bind.resume_pending(saved_key, |key| fetch_report(key.to_owned()));
+    /// ```
+    pub fn resume_pending<Fut>(
+        &mut self,
+        key: Option<String>,
+        rehydrate: impl FnOnce(&str) -> Fut,
+    ) -> bool
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let Some(key) = key else {
+            return false;
+        };
+
+        let fut = rehydrate(&key);
+        self.request_keyed(key, fut);
+        true
+    }
+
+    /// Starts a request that supersedes the previous one, as needed for type-ahead search.
+    ///
+    /// If the task started by the previous `search_latest` is still running, it is aborted
+    /// (its future is dropped at the next await point), so no work is wasted on outdated
+    /// queries. In addition, only the newest request's result is ever received: a superseded
+    /// response can never overwrite a newer one, even if it arrives last.
+    ///
+    /// The previous data stays readable while the new request is `Pending`.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.text_edit_singleline(&mut self.query).changed() {
+    ///     let query = self.query.clone();
+    ///     self.results.search_latest(async move { search(&query).await });
+    /// }
+    /// ```
+    pub fn search_latest<Fut>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        // Poll first, so a result that has already arrived is not lost to the abort.
+        self.poll();
+        self.abort_task();
+        self.request(f);
+    }
+
+    /// Starts an asynchronous operation that reports its progress along the way.
+    ///
+    /// `f` receives a `ProgressSender` through which the future can report values of any type
+    /// `P` (e.g. a fraction, or bytes sent and total). The latest one is available through
+    /// `progress` until the next operation starts; the result is delivered as with `request`.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// bind.request_with_progress(|progress| async move {
+    ///     for (i, chunk) in chunks.iter().enumerate() {
+    ///         upload(chunk).await?;
+    ///         progress.send((i + 1) as f32 / chunks.len() as f32);
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// if let Some(fraction) = bind.progress::<f32>() {
+    ///     ui.add(egui::ProgressBar::new(*fraction));
+    /// }
+    /// ```
+    pub fn request_with_progress<P, Fut>(&mut self, f: impl FnOnce(ProgressSender<P>) -> Fut)
+    where
+        P: Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.request(f(ProgressSender {
+            tx,
+            repaint: self.repainter(),
+            _marker: PhantomData,
+        }));
+        self.progress_recv = Some(rx);
+    }
+
+    /// Returns the latest progress reported through the `ProgressSender` of
+    /// `request_with_progress`.
+    ///
+    /// Returns `None` if no progress has been reported yet, or if `P` is not the type the
+    /// operation reports.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn progress<P: 'static>(&mut self) -> Option<&P> {
+        self.poll();
+        self.progress.as_deref()?.downcast_ref::<P>()
+    }
+
+    /// Starts an asynchronous operation, coalescing calls that come in quick succession.
+    ///
+    /// If at least `debounce` has passed since the previous call, the future is started right
+    /// away. Otherwise it is queued to start once `debounce` has passed without another call,
+    /// replacing any request queued before. A burst of calls (e.g. one per keystroke) thus runs
+    /// only its first and its last request: the first starts at once, and the last starts when
+    /// the burst is over, superseding the first if it is still running. The calls in between
+    /// never start, so they are not counted by `count_executed`. The last one always runs, even
+    /// if no further call follows.
+    ///
+    /// The window is measured on the frame clock, and the previous data stays readable while
+    /// a request waits for it. Only the newest request's result is received, as with
+    /// `search_latest`. An explicit `request`, `cancel` or `clear` drops the queued request.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.text_edit_singleline(&mut self.query).changed() {
+    ///     let query = self.query.clone();
+    ///     self.results.request_debounced(
+    ///         || async move { search(&query).await },
+    ///         Duration::from_millis(300),
+    ///     );
+    /// }
+    /// ```
+    pub fn request_debounced<Fut>(&mut self, f: impl FnOnce() -> Fut, debounce: Duration)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        let now = self.now();
+        let since_last = now - self.debounce_last;
+        self.debounce_last = now;
+
+        if since_last >= debounce.as_secs_f64() {
+            self.search_latest(f());
+            return;
+        }
+
+        // Replace the queued request, leaving the one already running alone.
+        let (tx, rx) = oneshot::channel();
+        let (abort_tx, abort_rx) = oneshot::channel();
+        self.debounced = Some(DebouncedRequest {
+            start_at: now + debounce.as_secs_f64(),
+            // The request is counted when it starts.
+            task: Mutex::new(Box::pin(abortable(
+                Self::req_inner(
+                    f(),
+                    Completion::Result,
+                    tx,
+                    self.times_executed + 1,
+                    self.default_timeout,
+                    self.repainter(),
+                ),
+                abort_rx,
+            ))),
+            recv: rx,
+            abort: abort_tx,
+        });
+
+        // Make sure a frame happens when the window has passed.
+        #[cfg(feature = "egui")]
+        if let Some(ctx) = self.ctx() {
+            ctx.request_repaint_after(debounce);
+        }
+    }
+
+    /// Starts an asynchronous operation that tries an ordered list of sources in turn.
+    ///
+    /// `first` is tried first, then each of `rest` in order, all inside a single spawned task,
+    /// stopping at the first `Ok`. If every source fails, the `Bind` finishes with the last
+    /// error. The `Bind` stays `Pending` for the whole chain. This suits tiered loading such as
+    /// local cache, then CDN, then origin. Taking the first source separately ensures there is
+    /// always one to try.
+    ///
+    /// Use `fallback_source` to find out which source succeeded; `first` has index `0`.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Sources built from config, e.g. a list of mirrors.
+    /// let mut sources = mirrors.iter().map(|url| {
+    ///     let url = url.clone();
+    ///     move || fetch_from(url)
+    /// });
+    /// if let Some(first) = sources.next() {
+    ///     bind.request_fallback(first, sources);
+    /// }
+    /// ```
+    pub fn request_fallback<F, Fut>(&mut self, first: F, rest: impl IntoIterator<Item = F>)
+    where
+        F: FnOnce() -> Fut + MaybeSend + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let rest: Vec<F> = rest.into_iter().collect();
+        let succeeded = Arc::new(AtomicUsize::new(usize::MAX));
+        let task_succeeded = succeeded.clone();
+
+        self.request(async move {
+            let mut index = 0;
+            let mut result = first().await;
+            for source in rest {
+                if result.is_ok() {
+                    break;
+                }
+                index += 1;
+                result = source().await;
+            }
+
+            if result.is_ok() {
+                task_succeeded.store(index, Ordering::Relaxed);
+            }
+            result
+        });
+        self.fallback_source = Some(succeeded);
+    }
+
+    /// Starts an operation that is retried with exponential backoff when it fails.
+    ///
+    /// `make_fut` is called for each attempt, up to `max_attempts` times in total. After the
+    /// `n`-th failed attempt, the task waits `base_delay * 2^(n - 1)` before the next one. The
+    /// `Bind` stays `Pending` throughout and only finishes with `Err` once the last attempt
+    /// has failed. Use `current_attempt` to show progress such as "Retrying (2/5)…".
+    ///
+    /// A `max_attempts` of `0` is treated as `1`, so the operation always runs at least once.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// bind.request_retry(fetch_my_ip, 5, Duration::from_millis(500));
+    ///
+    /// if bind.is_pending() && bind.current_attempt() > 1 {
+    ///     ui.label(format!("Retrying ({}/5)…", bind.current_attempt()));
+    /// }
+    /// ```
+    pub fn request_retry<F, Fut>(&mut self, make_fut: F, max_attempts: usize, base_delay: Duration)
+    where
+        F: Fn() -> Fut + MaybeSend + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let max_attempts = max_attempts.max(1);
+        let attempt = Arc::new(AtomicUsize::new(1));
+        let task_attempt = attempt.clone();
+        let repaint = self.repainter();
+
+        self.request(async move {
+            let mut delay = base_delay;
+            let mut n = 1;
+            loop {
+                task_attempt.store(n, Ordering::Relaxed);
+                let result = make_fut().await;
+                if result.is_ok() || n >= max_attempts {
+                    return result;
+                }
+
+                warn!("Attempt {n}/{max_attempts} failed, retrying in {delay:?}.");
+                sleep(delay).await;
+                delay = delay.saturating_mul(2);
+                n += 1;
+                // Let the UI show the new attempt number.
+                repaint.request();
+            }
+        });
+        self.attempt = Some(attempt);
+    }
+
+    /// Returns the number of the attempt currently running (or last run) in `request_retry`,
+    /// starting at `1`. Returns `0` if the latest operation was not started with it.
+    pub fn current_attempt(&self) -> usize {
+        self.attempt
+            .as_ref()
+            .map_or(0, |attempt| attempt.load(Ordering::Relaxed))
+    }
+
+    /// Starts an operation that is retried until it succeeds or a total time budget runs out.
+    ///
+    /// Each attempt is built by `make` and bounded by `per_attempt`; a failed or timed-out
+    /// attempt is retried after a short pause. Once `total_budget` has elapsed, the `Bind`
+    /// finishes with an error converted from `BudgetExhausted`, which carries the number of
+    /// attempts and the last attempt's error. Use `remaining_budget` to show the time left.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // `Bind<Report, String>`: `String` implements `From<BudgetExhausted<String>>`.
+    /// bind.request_with_budget(fetch_report, Duration::from_secs(10), Duration::from_secs(3));
+    /// ```
+    pub fn request_with_budget<F, Fut>(
+        &mut self,
+        make: F,
+        total_budget: Duration,
+        per_attempt: Duration,
+    ) where
+        F: Fn() -> Fut + MaybeSend + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: From<BudgetExhausted<E>> + MaybeSend,
+    {
+        self.request(async move {
+            let mut attempts = 0;
+            let mut last_error = None;
+
+            let (attempts_out, last_error_out) = (&mut attempts, &mut last_error);
+            let attempt_loop = async move {
+                loop {
+                    *attempts_out += 1;
+                    *last_error_out = None;
+                    match timeout(per_attempt, Box::pin(make())).await {
+                        Some(Ok(value)) => return Ok(value),
+                        Some(Err(err)) => *last_error_out = Some(err),
+                        None => {}
+                    }
+                    sleep(BUDGET_RETRY_DELAY).await;
+                }
+            };
+
+            match timeout(total_budget, Box::pin(attempt_loop)).await {
+                Some(result) => result,
+                None => Err(E::from(BudgetExhausted {
+                    attempts,
+                    last_error,
+                })),
+            }
+        });
+        self.budget_deadline = Some(self.last_start_time + total_budget.as_secs_f64());
+    }
+
+    /// Returns the seconds left in the budget of a `Pending` `request_with_budget` operation.
+    ///
+    /// This is measured on the frame clock, like every other `Bind` timestamp, while the
+    /// budget itself is enforced by a timer in the task. The two agree as long as frames are
+    /// drawn, but the value only decreases from frame to frame and may briefly read `0.0`
+    /// before the result arrives. Returns `None` if no such operation is running.
+    pub fn remaining_budget(&self) -> Option<f64> {
+        if !matches!(self.state, State::Pending) {
+            return None;
+        }
+
+        self.budget_deadline
+            .map(|deadline| (deadline - self.now()).max(0.0))
+    }
+
+    /// Returns the seconds left before the `Pending` operation is dropped, by the budget of
+    /// `request_with_budget` or by `BindBuilder::default_timeout`, whichever runs out first.
+    ///
+    /// Like `remaining_budget`, this is measured on the frame clock. Returns `None` if no
+    /// operation is running, or if the running one has no time limit (e.g. one started by
+    /// `request_blocking`, which cannot be timed out).
+    pub fn remaining_time(&self) -> Option<f64> {
+        if !matches!(self.state, State::Pending) {
+            return None;
+        }
+
+        let deadline = match (self.budget_deadline, self.timeout_deadline) {
+            (Some(budget), Some(timeout)) => budget.min(timeout),
+            (budget, timeout) => budget.or(timeout)?,
+        };
+        Some((deadline - self.now()).max(0.0))
+    }
+
+    /// Internal helper that records when the task being started is dropped by
+    /// `default_timeout`, for `remaining_time`.
+    fn arm_timeout(&mut self) {
+        self.timeout_deadline = self
+            .default_timeout
+            .map(|limit| self.last_start_time + limit.as_secs_f64());
+    }
+
+    /// Fetches data that depends on a set of inputs, refetching whenever any of them changes.
+    ///
+    /// Like a dependency array of a React effect: call this every frame with the current
+    /// inputs. If their hash differs from the previous call (or there is no stored hash yet,
+    /// e.g. after `clear`), the `Bind` is refreshed with `f`. Otherwise nothing happens.
+    /// Returns `true` if a request was started.
+    ///
+    /// `deps` can be anything hashable: a single value, a tuple of several inputs, or a
+    /// slice. Only a hash of it is stored, so the comparison is cheap regardless of its size.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let deps = (self.user_id, self.filter.clone(), self.sort);
+    /// self.rows.request_deps(&deps, || fetch_rows(deps.clone()));
+    /// ```
+    pub fn request_deps<K, Fut>(&mut self, deps: &K, f: impl FnOnce() -> Fut) -> bool
+    where
+        K: Hash + ?Sized,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        let mut hasher = DefaultHasher::new();
+        deps.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if self.deps_hash == Some(hash) {
+            return false;
+        }
+
+        self.refresh(f());
+        self.deps_hash = Some(hash);
+        true
+    }
+
+    /// Returns the data for `key`, fetching it with `f` whenever the key changes.
+    ///
+    /// The key of the latest request is stored in the `Bind`. If `key` differs from it (or no
+    /// key was stored yet), the `Bind` is refreshed, dropping the data of the old key. If the
+    /// key is unchanged but the `Bind` is `Idle`, a request is started. Otherwise the cached
+    /// data is returned and `f` is not called.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ui.text_edit_singleline(&mut self.input_ip);
+    /// let ip = self.input_ip.clone();
+    /// if let Some(Ok(info)) = self.ip_info.request_for_key(ip.clone(), || lookup(ip)) {
+    ///     ui.label(info);
+    /// }
+    /// ```
+    pub fn request_for_key<K, Fut>(
+        &mut self,
+        key: K,
+        f: impl FnOnce() -> Fut,
+    ) -> &Option<Result<T, E>>
+    where
+        K: PartialEq + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        let changed = self
+            .last_key
+            .as_ref()
+            .and_then(|last| last.downcast_ref::<K>())
+            != Some(&key);
+
+        if changed {
+            self.refresh(f());
+            self.last_key = Some(Box::new(key));
+        } else if matches!(self.state, State::Idle) {
+            self.request(f());
+            self.last_key = Some(Box::new(key));
+        }
+
+        &self.data
+    }
+
+    /// Stores a locally produced value immediately and persists it in the background.
+    ///
+    /// `local_value` becomes the `Bind`'s data at once, as `Finished(Ok)`, so the UI updates
+    /// instantly. `sync` is spawned to persist it (e.g. upload it to a server), and its outcome
+    /// is tracked separately through `sync_status` and `sync_error` without touching the
+    /// displayed value. Any `Pending` operation is superseded.
+    ///
+    /// Only the sync counts as an execution: it adds to `count_executed` when it starts and
+    /// to `success_count` or `failure_count` when it ends. Storing the local value records
+    /// no latency or outcome of its own.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.button("Save").clicked() {
+    ///     let note = self.draft.clone();
+    ///     self.note.request_local_first(note.clone(), upload_note(note));
+    /// }
+    /// match self.note.sync_status() {
+    ///     Some(SyncStatus::Syncing) => { ui.spinner(); }
+    ///     Some(SyncStatus::Failed) => { ui.label("Not synced"); }
+    ///     _ => {}
+    /// }
+    /// ```
+    pub fn request_local_first<Fut>(&mut self, local_value: T, sync: Fut)
+    where
+        Fut: Future<Output = Result<(), E>> + MaybeSend + 'static,
+        E: MaybeSend,
+    {
+        self.poll();
+        self.begin_request();
+        self.recv = None;
+        self.paced = None;
+        self.debounced = None;
+        self.store_filled(Ok(local_value));
+
+        let (tx, rx) = oneshot::channel();
+        let repaint = self.repainter();
+        self.spawn_task(async move {
+            if tx.send(sync.await).is_ok() {
+                repaint.request();
+            }
+        });
+        self.sync_recv = Some(rx);
+        self.sync_status = Some(SyncStatus::Syncing);
+        self.sync_error = None;
+    }
+
+    /// Returns the status of the latest `request_local_first` sync, or `None` if there is none.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn sync_status(&mut self) -> Option<SyncStatus> {
+        self.poll();
+        self.sync_status
+    }
+
+    /// Returns the error of the latest `request_local_first` sync, if it failed.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn sync_error(&mut self) -> Option<&E> {
+        self.poll();
+        self.sync_error.as_ref()
+    }
+
+    /// Returns the index of the source that succeeded in the latest `request_fallback` chain.
+    ///
+    /// Returns `None` while the chain is still running, if every source failed, or if the
+    /// latest request was not started with `request_fallback`.
+    pub fn fallback_source(&self) -> Option<usize> {
+        self.fallback_source
+            .as_ref()
+            .map(|index| index.load(Ordering::Relaxed))
+            .filter(|&index| index != usize::MAX)
+    }
+
+    /// Queues an asynchronous operation to start when the shared `Pacer` admits it.
+    ///
+    /// The `Bind` keeps its current state and data until its slot comes up, at which point
+    /// `f` is spawned as if by `request`. This spreads a burst of requests (e.g. a "refresh all"
+    /// button) over time at the pacer's rate. Use `paced_position` to show the queue position.
+    ///
+    /// Calling this again while a request is still queued replaces it and takes a new slot.
+    /// An explicit `request` or `clear` drops the queued request.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_paced<Fut>(&mut self, pacer: &Pacer, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        let now = self.now();
+        let slot = pacer.reserve(now);
+        let (tx, rx) = oneshot::channel();
+
+        self.paced = Some(PacedRequest {
+            slot,
+            interval: pacer.interval(),
+            // The request is counted when its slot comes up.
+            task: Mutex::new(Box::pin(Self::req_inner(
+                f,
+                Completion::Result,
+                tx,
+                self.times_executed + 1,
+                self.default_timeout,
+                self.repainter(),
+            ))),
+            recv: rx,
+        });
+
+        // Make sure a frame happens when the slot comes up.
+        #[cfg(feature = "egui")]
+        if let Some(ctx) = self.ctx() {
+            #[allow(clippy::cast_possible_truncation)]
+            ctx.request_repaint_after_secs((slot - now) as f32);
+        }
+    }
+
+    /// Returns how many pacer slots remain before the queued paced request starts, or `None`
+    /// if no request is queued. `0` means it starts on the next poll.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn paced_position(&self) -> Option<usize> {
+        let now = self.now();
+        self.paced
+            .as_ref()
+            .map(|paced| ((paced.slot - now) / paced.interval).ceil().max(0.0) as usize)
+    }
+
+    /// Starts an asynchronous operation that is shared with any other `Bind` requesting the
+    /// same `key` at the same time.
+    ///
+    /// This is `request_coalesced` with a string key.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_deduped<Fut>(&mut self, key: impl Into<String>, f: impl FnOnce() -> Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: Clone + Send,
+        E: Clone + Send,
+    {
+        self.request_coalesced(key.into(), f);
+    }
+
+    /// Starts an asynchronous operation that is shared with any other `Bind` requesting an
+    /// equal `key` at the same time.
+    ///
+    /// If a coalesced request for `key` is already in flight, this `Bind` attaches to it
+    /// instead of calling `f`, and all attached binds complete together with a clone of the
+    /// single result. Once that result is delivered the key is free again, so a later call
+    /// starts a fresh request. This saves identical requests when many widgets show the same
+    /// entity, e.g. the rows of a list.
+    ///
+    /// Keys are scoped by their own type and the `Result<T, E>` type, so two binds with
+    /// different types never share a task. Because the result is fanned out, `T` and `E` must
+    /// be `Clone + Send`.
+    ///
+    /// Cancelling or dropping an attached `Bind` only detaches it; the shared future is dropped
+    /// once no `Bind` waits for it any more. The `default_timeout` of the `Bind` that started
+    /// the request applies to all of them. If the future panics, every attached `Bind` leaves
+    /// `Pending` as described for `poll`, and the key is free again.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// for row in &mut self.rows {
+    ///     let id = row.author_id;
+    ///     row.author.request_coalesced(id, || fetch_user(id));
+    /// }
+    /// ```
+    pub fn request_coalesced<K, Fut>(&mut self, key: K, f: impl FnOnce() -> Fut)
+    where
+        K: Hash + Eq + Clone + Send + 'static,
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: Clone + Send,
+        E: Clone + Send,
+    {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let slot = (TypeId::of::<(K, Result<T, E>)>(), hasher.finish());
+        let (tx, rx) = self.prepare_channel();
+        self.recv = Some(rx);
+
+        let mut in_flight = IN_FLIGHT.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(bucket) = in_flight
+            .entry(slot)
+            .or_insert_with(|| Box::new(Bucket::<K, T, E>::new()))
+            .downcast_mut::<Bucket<K, T, E>>()
+        else {
+            // Unreachable, since the types are part of the slot.
+            return;
+        };
+        if let Some((_, waiters)) = bucket.iter_mut().find(|(waiting, _)| *waiting == key) {
+            waiters.push(tx);
+            return;
+        }
+        bucket.push((key.clone(), vec![tx]));
+        drop(in_flight);
+
+        // Created before `f` runs, so that even a panic in `f` frees the key.
+        let mut guard = InFlightGuard::<K, T, E> {
+            slot,
+            key: Some(key),
+            _marker: PhantomData,
+        };
+        let fut = f();
+        let limit = self.default_timeout;
+        self.arm_timeout();
+        let repaint = self.repainter();
+        self.spawn_task(async move {
+            use std::task::Poll;
+
+            // `None` if the request timed out.
+            let mut shared = std::pin::pin!(async move {
+                match limit {
+                    Some(limit) => timeout(limit, Box::pin(fut)).await,
+                    None => Some(fut.await),
+                }
+            });
+            let outcome = std::future::poll_fn(|cx| {
+                if let Poll::Ready(outcome) = shared.as_mut().poll(cx) {
+                    return Poll::Ready(Some(outcome));
+                }
+                guard.poll_abandoned(cx).map(|()| None)
+            })
+            .await;
+
+            // Without an outcome, no `Bind` is left to deliver one to.
+            let Some(outcome) = outcome else {
+                return;
+            };
+            for tx in guard.take_waiters() {
+                // A waiter may have been dropped or refreshed in the meantime.
+                let _ = tx.send(
+                    outcome
+                        .clone()
+                        .map_or(Completion::TimedOut, Completion::Result),
+                );
+            }
+            repaint.request();
+        });
+    }
+
+    /// Starts an asynchronous operation that falls back to the current data on error.
+    ///
+    /// Behaves like `request`, except that if the operation fails while the `Bind` holds an
+    /// `Ok` value, that value is kept and presented as `Finished(Ok)` instead of surfacing the
+    /// error. `last_refresh_failed()` then reports `true` so the UI can mark the data as stale.
+    /// The error is only surfaced as `Finished(Err)` when there is no cached value at all.
+    ///
+    /// This gives "offline-first" behavior: once something has loaded, a flaky connection never
+    /// replaces it with an error.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_with_stale_fallback<Fut>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(f);
+        self.stale_fallback = true;
+    }
+
+    /// Returns `true` if the most recent refresh failed and the presented data is a stale
+    /// value kept by `request_with_stale_fallback`.
+    pub const fn last_refresh_failed(&self) -> bool {
+        self.refresh_failed
+    }
+
+    /// Shows a cached value instantly and revalidates it in the background
+    /// ("stale-while-revalidate").
+    ///
+    /// If `cached` is `Some`, it is stored immediately as the `Bind`'s data and a background
+    /// refresh via `f` starts right away. The cached value stays readable through `read`,
+    /// `read_or_request` and friends until the fresh result replaces it, while `state()`
+    /// reports `Pending`. If `cached` is `None`, this behaves exactly like `request`.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_swr<Fut>(&mut self, cached: Option<Result<T, E>>, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if let Some(cached) = cached {
+            self.data = Some(cached);
+            self.last_complete_time = self.now();
+            self.completed_on_frame = self.frame_index();
+            self.refresh_failed = false;
+        }
+
+        self.request(f);
+    }
+
+    /// Loads a value cached in the browser's `localStorage`, then refreshes it in the background.
+    ///
+    /// If `key` holds a JSON value that deserializes into `T`, it is shown immediately and `f`
+    /// revalidates it, like `request_swr`. Successful results of `f` are written back under
+    /// `key`, so page reloads are instant and the last value is available offline. Missing,
+    /// stale or incompatible cache entries are ignored and the request runs normally.
+    ///
+    /// Errors are never cached. If `localStorage` is unavailable (e.g. disabled by the user),
+    /// this behaves like `request`.
+    ///
+    /// This method calls `poll()` internally.
+    #[cfg(all(target_family = "wasm", feature = "local-storage"))]
+    pub fn request_ls_cached<Fut>(&mut self, key: &str, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + 'static,
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let storage = web_sys::window().and_then(|window| window.local_storage().ok().flatten());
+
+        // A cache entry that no longer matches `T` is simply skipped and overwritten later.
+        let cached = storage
+            .as_ref()
+            .and_then(|storage| storage.get_item(key).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .map(Ok);
+
+        let key = key.to_owned();
+        self.request_swr(cached, async move {
+            let result = f.await;
+
+            if let (Some(storage), Ok(data)) = (&storage, &result) {
+                match serde_json::to_string(data) {
+                    Ok(json) => {
+                        if storage.set_item(&key, &json).is_err() {
+                            warn!("Failed to write `{key}` to localStorage.");
+                        }
+                    }
+                    Err(err) => warn!("Failed to serialize `{key}` for localStorage: {err}"),
+                }
+            }
+
+            result
+        });
+    }
+
+    /// Connects to a server-sent events endpoint and binds its latest event.
+    ///
+    /// Every `data` payload is converted with `parse`, and the newest result replaces the
+    /// `Bind`'s data on the next poll. The first event moves the `Bind` from `Pending` to
+    /// `Finished`; it then stays `Finished` while further events keep arriving. A `parse` error
+    /// is presented like any other error and is replaced by the next good event.
+    ///
+    /// Lost connections are re-established with exponential backoff; `connection_status`
+    /// reports the current status. The connection is closed when the `Bind` is cleared,
+    /// dropped, or starts another request.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if self.scores.is_idle() {
+    ///     self.scores.request_sse("https://example.com/scores", |data| {
+    ///         serde_json::from_str::<Score>(data).map_err(|e| e.to_string())
+    ///     });
+    /// }
+    /// ```
+    #[cfg(feature = "sse")]
+    pub fn request_sse<P>(&mut self, url: &str, parse: P)
+    where
+        P: Fn(&str) -> Result<T, E> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+        self.begin_request();
+        self.recv = None;
+        self.paced = None;
+        self.debounced = None;
+
+        let (tx, live) = LiveFeed::new(self.backpressure, self.repainter());
+
+        self.spawn_task(run_sse(
+            url.to_owned(),
+            parse,
+            tx,
+            live.status.clone(),
+            self.repainter(),
+        ));
+        self.live = Some(live);
+    }
+
+    /// Sets how live sources (such as `request_sse`) deliver values that arrive faster than
+    /// the UI polls. Takes effect for live sources started afterwards.
+    ///
+    /// Defaults to `BackpressureMode::KeepLatest`.
+    pub const fn set_backpressure(&mut self, mode: BackpressureMode) {
+        self.backpressure = mode;
+    }
+
+    /// Returns the status of the live source started by `request_sse`, or `None` if there
+    /// is no live source.
+    pub fn connection_status(&self) -> Option<ConnectionStatus> {
+        self.live
+            .as_ref()
+            .map(|live| ConnectionStatus::from_u8(live.status.load(Ordering::Acquire)))
+    }
+
+    /// Runs a CPU-heavy closure on the `rayon` thread pool and binds its result.
+    ///
+    /// This is meant for work that parallelizes well (e.g. parsing a large CSV across cores)
+    /// and would otherwise stall both the UI and the async runtime. The closure can use
+    /// `rayon`'s parallel iterators internally. The `Bind` lifecycle is identical to `request`.
+    ///
+    /// The global `rayon` pool defaults to one thread per logical CPU and is shared with any
+    /// other `rayon` work in the process. To size it, call
+    /// `rayon::ThreadPoolBuilder::new().num_threads(n).build_global()` once at startup, before
+    /// the first parallel request.
+    ///
+    /// On WASM there are no threads, so the closure runs synchronously on the calling thread
+    /// and its result is picked up on the next poll.
+    ///
+    /// This method calls `poll()` internally.
+    #[cfg(feature = "rayon")]
+    pub fn request_parallel<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let (tx, rx) = self.prepare_channel();
+
+        #[cfg(not(target_family = "wasm"))]
+        let repaint = self.repainter();
+        #[cfg(not(target_family = "wasm"))]
+        rayon::spawn(move || {
+            if matches!(tx.send(Completion::Result(f())), Ok(())) {
+                repaint.request();
+            } else {
+                warn!("Parallel result was dropped because the receiver was gone.");
+            }
+        });
+
+        #[cfg(target_family = "wasm")]
+        let _ = tx.send(Completion::Result(f()));
+
+        self.recv = Some(rx);
+    }
+
+    /// Runs a blocking closure on the runtime's blocking thread pool and binds its result.
+    ///
+    /// This is meant for synchronous, CPU-bound or blocking work (e.g. decoding an image or
+    /// parsing a large file) that would stall the async runtime if awaited in `request`. With
+    /// `runtime-tokio`, the closure runs on the runtime set with `BindBuilder::runtime` or
+    /// `init_runtime`, or else on `ASYNC_RUNTIME`; `runtime-async-std` and `runtime-smol` use
+    /// their own blocking pools. The `Bind` lifecycle is identical to `request`, but a running closure
+    /// cannot be cancelled or timed out; its result is simply discarded if the `Bind` moved on.
+    ///
+    /// On WASM there is no blocking pool, so the closure runs synchronously on the calling
+    /// thread (with a warning, since it blocks the UI) and its result is picked up on the next
+    /// poll.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.button("Open").clicked() {
+    ///     let bytes = bytes.clone();
+    ///     self.image.request_blocking(move || decode_png(&bytes).map_err(|e| e.to_string()));
+    /// }
+    /// ```
+    pub fn request_blocking<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> Result<T, E> + Send + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let (tx, rx) = self.prepare_channel();
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let repaint = self.repainter();
+            let job = move || {
+                if matches!(tx.send(Completion::Result(f())), Ok(())) {
+                    repaint.request();
+                } else {
+                    warn!("Blocking result was dropped because the receiver was gone.");
+                }
+            };
+
+            #[cfg(feature = "runtime-tokio")]
+            self.runtime
+                .clone()
+                .or_else(|| RUNTIME_HANDLE.get().cloned())
+                .unwrap_or_else(|| ASYNC_RUNTIME.handle().clone())
+                .spawn_blocking(job);
+
+            #[cfg(feature = "runtime-async-std")]
+            async_std::task::spawn_blocking(job);
+
+            #[cfg(feature = "runtime-smol")]
+            smol::unblock(job).detach();
+        }
+
+        #[cfg(target_family = "wasm")]
+        {
+            warn!("request_blocking has no blocking pool on WASM; running the closure inline.");
+            let _ = tx.send(Completion::Result(f()));
+        }
+
+        self.recv = Some(rx);
+    }
+
+    /// Starts a conditional request, such as an HTTP request with `If-None-Match`.
+    ///
+    /// `f` receives the validator token stored from the previous response (if any) and
+    /// returns either `CondResult::NotModified`, in which case the existing data is kept and
+    /// only its completion time is bumped, or `CondResult::Modified`, which replaces the data
+    /// and stores the new token. This avoids re-downloading unchanged payloads.
+    ///
+    /// The token is dropped together with the data (e.g. by `clear`), so the next request is
+    /// unconditional. If a `NotModified` arrives when there is no data to keep, the `Bind`
+    /// returns to `Idle`. Otherwise the operation runs like one started by `request`: it honours
+    /// the default timeout and can be cancelled.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// bind.request_conditional(|etag| async move {
+    ///     let mut req = client.get(url);
+    ///     if let Some(etag) = etag {
+    ///         req = req.header("If-None-Match", etag);
+    ///     }
+    ///     let resp = req.send().await.map_err(|e| e.to_string())?;
+    ///     if resp.status() == reqwest::StatusCode::NOT_MODIFIED {
+    ///         return Ok(CondResult::NotModified);
+    ///     }
+    ///     let etag = resp.headers().get("ETag").and_then(|v| v.to_str().ok()).map(String::from);
+    ///     let body = resp.text().await.map_err(|e| e.to_string())?;
+    ///     Ok(CondResult::Modified(body, etag))
+    /// });
+    /// ```
+    pub fn request_conditional<Fut>(&mut self, f: impl FnOnce(Option<String>) -> Fut)
+    where
+        Fut: Future<Output = Result<CondResult<T>, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        // A mocked response is a full result, as if the resource was modified without a token.
+        #[cfg(feature = "test-util")]
+        if self.request_mocked() {
+            return;
+        }
+
+        let fut = f(self.validator_token.clone());
+        let task = self.start_request(fut, Completion::Conditional);
+        self.spawn_task(task);
+    }
+
+    /// Subscribes to the completions of this `Bind`.
+    ///
+    /// The returned `BindSubscription` reports a change every time this `Bind` finishes an
+    /// operation or is filled, so derived binds and widgets know when to recompute. Any number
+    /// of subscriptions can be created; each tracks what it has seen independently.
+    ///
+    /// Note that completions are only observed when this `Bind` is polled.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Once, when setting up:
+    /// let mut sub = self.users.subscribe();
+    ///
+    /// // Every frame:
+    /// if sub.changed() {
+    ///     self.user_count.clear();
+    /// }
+    /// ```
+    pub fn subscribe(&mut self) -> BindSubscription {
+        let generation = self
+            .generation
+            .get_or_insert_with(|| Arc::new(AtomicU64::new(0)))
+            .clone();
+        let seen = generation.load(Ordering::Acquire);
+
+        BindSubscription { generation, seen }
+    }
+
+    /// Returns the validator token (e.g. `ETag`) stored by the latest `request_conditional`.
+    pub fn validator_token(&self) -> Option<&str> {
+        self.validator_token.as_deref()
+    }
+
+    /// Starts an asynchronous operation whose successful result must pass a validator.
+    ///
+    /// After `f` resolves with `Ok(value)`, `validate` runs on the task (not the UI thread) and
+    /// may downgrade the result to `Err`, e.g. for a response that returned HTTP 200 but is
+    /// semantically invalid. The `Bind` then finishes with the validator's error.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn request_validated<Fut>(
+        &mut self,
+        f: Fut,
+        validate: impl FnOnce(&T) -> Result<(), E> + MaybeSend + 'static,
+    ) where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request(async move {
+            let value = f.await?;
+            validate(&value)?;
+            Ok(value)
+        });
+    }
+
+    /// Starts an asynchronous operation only if the global connectivity probe reports online.
+    ///
+    /// If the app is offline (see `set_online_checker`), `f` is not called, the `Bind` is left
+    /// untouched and `is_offline()` returns `true` until a request is started again. A repaint
+    /// is scheduled shortly after so the request fires once connectivity returns.
+    ///
+    /// # Returns
+    /// `true` if the request was started.
+    pub fn request_if_online<Fut>(&mut self, f: impl FnOnce() -> Fut) -> bool
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        if is_online() {
+            self.request(f());
+            true
+        } else {
+            self.offline = true;
+
+            #[cfg(feature = "egui")]
+            if let Some(ctx) = self.ctx() {
+                ctx.request_repaint_after_secs(OFFLINE_RECHECK_SECS);
+            }
+
+            false
+        }
+    }
+
+    /// Returns `true` if the latest operation was dropped for exceeding the timeout set with
+    /// `BindBuilder::default_timeout`.
+    ///
+    /// A timed-out `Bind` keeps the data of an earlier operation, if any, and is `Idle`
+    /// otherwise.
+    pub const fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Returns `true` if the most recent automatic request was skipped because the app was
+    /// offline. See `set_online_checker`.
+    pub const fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Requests an operation to run periodically.
+    ///
+    /// If the `Bind` is not `Pending` and more than `secs` seconds have passed since the
+    /// last completion, a new request is started by calling `f`. If the last completion was an
+    /// error, the wait is at least the minimum error display time (see `set_min_error_display`).
+    ///
+    /// # Returns
+    /// The time in seconds remaining until the next scheduled refresh. A negative value
+    /// indicates a refresh is overdue.
+    pub fn request_every_sec<Fut>(&mut self, f: impl FnOnce() -> Fut, secs: f64) -> f64
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let since_completed = self.since_completed();
+        let secs = self.auto_request_delay(secs);
+
+        if self.get_state() != State::Pending && since_completed > secs {
+            self.request_if_online(f);
+        }
+
+        secs - since_completed
+    }
+
+    /// Sets the minimum time, in seconds, that an error stays visible before automatic
+    /// request methods (such as `request_every_sec`) may refetch.
+    ///
+    /// This prevents errors from flashing briefly when a flaky-but-fast service is retried
+    /// quickly, giving the user time to read them. Explicit calls such as `refresh` or
+    /// `request` are not affected. Defaults to `0.0`.
+    pub const fn set_min_error_display(&mut self, secs: f64) {
+        self.min_error_display = secs;
+    }
+
+    /// Makes finished results ephemeral: once `Finished`, the `Bind` returns to `Idle` after
+    /// `secs` seconds, whether or not it is drawn. `None` (the default) keeps results.
+    ///
+    /// This suits command-style operations, such as showing "Saved!" briefly after a form is
+    /// submitted, without calling `clear` manually. Unlike `retain = false`, the timer does not
+    /// depend on drawing. A value of `0.0` keeps the result for a single frame.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut save = Bind::<(), String>::new(true);
+    /// save.auto_clear_after(Some(2.0));
+    ///
+    /// if matches!(save.read(), Some(Ok(()))) {
+    ///     ui.label("Saved!");
+    /// }
+    /// ```
+    pub const fn auto_clear_after(&mut self, secs: Option<f64>) {
+        self.auto_clear = secs;
+    }
+
+    /// Internal helper returning how long automatic requests must wait after the last
+    /// completion, given the caller's own `delay`.
+    fn auto_request_delay(&self, delay: f64) -> f64 {
+        if matches!(self.data, Some(Err(_))) {
+            delay.max(self.min_error_display)
+        } else {
+            delay
+        }
+    }
+
+    /// Clears any existing data and immediately starts a new async operation.
+    ///
+    /// If an operation was `Pending`, its result will be discarded. The background task is not
+    /// cancelled and will run to completion, unless `set_cancel_on_clear` is enabled.
+    ///
+    /// This is a convenience method equivalent to calling `clear()` followed by `request()`.
+    pub fn refresh<Fut>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.clear();
+        self.request(f);
+    }
+
+    /// Transforms the success value, turning this into a `Bind<U, E>`.
+    ///
+    /// A `Finished` result is transformed right away. If the operation is still `Pending`, an
+    /// adapter task transforms its result once it arrives, so the new `Bind` finishes as usual.
+    /// The retain policy, timing information, history and settings are carried over.
+    ///
+    /// Since `f` is called at most once, data kept from an earlier operation while another
+    /// one is `Pending` is dropped. A queued `request_paced` request and a live source (e.g.
+    /// `request_sse`) are typed by `T`, so they are dropped too.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let parsed: Bind<MyStruct, String> =
+    ///     raw.map(|value| serde_json::from_value(value).unwrap_or_default());
+    /// ```
+    pub fn map<U>(mut self, f: impl FnOnce(T) -> U + MaybeSend + 'static) -> Bind<U, E>
+    where
+        T: MaybeSend,
+        U: MaybeSend + 'static,
+        E: MaybeSend,
+    {
+        let sync_recv = self.sync_recv.take();
+        let sync_error = self.sync_error.take();
+
+        let mut mapped = self.map_result(|result| result.map(f));
+        mapped.sync_recv = sync_recv;
+        mapped.sync_error = sync_error;
+        mapped
+    }
+
+    /// Transforms the error value, turning this into a `Bind<T, E2>`.
+    ///
+    /// This behaves like `map`, but for the error. The outcome of a pending
+    /// `request_local_first` sync is still reported by `sync_status`, but its error is
+    /// discarded, since it has the old error type.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn map_err<E2>(self, f: impl FnOnce(E) -> E2 + MaybeSend + 'static) -> Bind<T, E2>
+    where
+        T: MaybeSend,
+        E: MaybeSend,
+        E2: MaybeSend + 'static,
+    {
+        self.map_result(|result| result.map_err(f))
+    }
+
+    /// Internal helper for `map` and `map_err` that moves everything but the sync outcome
+    /// into a `Bind` with new types.
+    fn map_result<U, E2>(
+        mut self,
+        f: impl FnOnce(Result<T, E>) -> Result<U, E2> + MaybeSend + 'static,
+    ) -> Bind<U, E2>
+    where
+        T: MaybeSend,
+        E: MaybeSend,
+        U: MaybeSend + 'static,
+        E2: MaybeSend + 'static,
+    {
+        self.poll();
+
+        // A mocked result would be lost with the old `Bind`, so deliver it to the adapter now.
+        #[cfg(feature = "test-util")]
+        if let Some(mock) = self.mock.take() {
+            let _ = mock.tx.send(Completion::Result(mock.result));
+        }
+
+        // Every field is listed, so that a new one cannot be forgotten here. Only the channels
+        // and values typed by `T` and `E` start empty; the result is carried over below.
+        let mut mapped = Bind {
+            drawn_time_last: self.drawn_time_last,
+            drawn_time_prev: self.drawn_time_prev,
+            drawn_on_frame: self.drawn_on_frame,
+            drawn_on_frame_prev: self.drawn_on_frame_prev,
+            data: None,
+            recv: None,
+            state: self.state,
+            state_entered_time: self.state_entered_time,
+            last_start_time: self.last_start_time,
+            last_complete_time: self.last_complete_time,
+            state_entered_frame: self.state_entered_frame,
+            started_on_frame: self.started_on_frame,
+            completed_on_frame: self.completed_on_frame,
+            retain: self.retain,
+            times_executed: self.times_executed,
+            successes: self.successes,
+            failures: self.failures,
+            completions: self.completions,
+            offline: self.offline,
+            stale_fallback: self.stale_fallback,
+            refresh_failed: self.refresh_failed,
+            optimistic: self.optimistic,
+            history: std::mem::take(&mut self.history),
+            latencies: std::mem::take(&mut self.latencies),
+            history_capacity: self.history_capacity,
+            paced: None,
+            debounced: None,
+            fallback_source: self.fallback_source.take(),
+            min_error_display: self.min_error_display,
+            validator_token: self.validator_token.take(),
+            generation: self.generation.take(),
+            live: None,
+            auto_clear: self.auto_clear,
+            backpressure: self.backpressure,
+            abort: self.abort.take(),
+            cancel_on_clear: self.cancel_on_clear,
+            default_timeout: self.default_timeout,
+            timed_out: self.timed_out,
+            min_pending: self.min_pending,
+            completion_tx: self.completion_tx.take(),
+            poll_error: self.poll_error,
+            #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+            runtime: self.runtime.take(),
+            #[cfg(feature = "egui")]
+            scope: self.scope.take(),
+            operation_key: self.operation_key.take(),
+            budget_deadline: self.budget_deadline,
+            timeout_deadline: self.timeout_deadline,
+            attempt: self.attempt.take(),
+            sync_recv: None,
+            sync_status: self.sync_status,
+            sync_error: None,
+            deps_hash: self.deps_hash,
+            last_key: self.last_key.take(),
+            debounce_last: self.debounce_last,
+            progress_recv: self.progress_recv.take(),
+            progress: self.progress.take(),
+            name: self.name.take(),
+            #[cfg(feature = "test-util")]
+            mock: None,
+        };
+
+        if let Some(recv) = self.recv.take() {
+            let (tx, rx) = oneshot::channel();
+            mapped.spawn_task(async move {
+                // If the original task is dropped, so is `tx`, which the new `Bind` handles.
+                if let Ok(completion) = recv.await {
+                    let _ = tx.send(map_completion(completion, f));
+                }
+            });
+            mapped.recv = Some(rx);
+        } else {
+            mapped.data = self.data.take().map(f);
+        }
+
+        if self.live.is_some() && matches!(mapped.state, State::Pending) {
+            mapped.settle_after_abort();
+        }
+        mapped
+    }
+
+    /// Consumes the `Bind` and returns its result if the operation is `Finished`.
+    ///
+    /// This is `take` for a `Bind` that is no longer needed, e.g. once loaded data is converted
+    /// into app state. Returns `None` if the operation is not `Finished`, in which case an
+    /// in-flight task is handled as if the `Bind` had been dropped.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn into_result(mut self) -> Option<Result<T, E>> {
+        self.poll();
+
+        if matches!(self.state, State::Finished) {
+            self.data.take()
+        } else {
+            None
+        }
+    }
+
+    /// Takes ownership of the result if the operation is `Finished`.
+    ///
+    /// If the state is `Finished`, this method returns `Some(result)`, consumes the data
+    /// internally, and resets the state to `Idle`. If the state is not `Finished`,
+    /// it returns `None`.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn take(&mut self) -> Option<Result<T, E>> {
+        self.poll();
+
+        if matches!(self.state, State::Finished) {
+            assert!(
+                self.data.is_some(),
+                "State was Finished but data was None. This indicates a bug."
+            );
+            self.set_state(State::Idle);
+            self.data.take()
+        } else {
+            None
+        }
+    }
+
+    /// Takes the success value if the operation is `Finished` with `Ok`.
+    ///
+    /// On success, the data is consumed and the state is reset to `Idle`, like `take`. If the
+    /// operation finished with an error, or is not `Finished`, this returns `None` and leaves
+    /// the `Bind` untouched, so a subsequent `take_err` can still retrieve the error.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn take_ok(&mut self) -> Option<T> {
+        self.poll();
+
+        if matches!(self.state, State::Finished) && matches!(self.data, Some(Ok(_))) {
+            self.set_state(State::Idle);
+            self.data.take().and_then(Result::ok)
+        } else {
+            None
+        }
+    }
+
+    /// Takes the error if the operation is `Finished` with `Err`.
+    ///
+    /// This is the counterpart of `take_ok`: a successful result is left untouched.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn take_err(&mut self) -> Option<E> {
+        self.poll();
+
+        if matches!(self.state, State::Finished) && matches!(self.data, Some(Err(_))) {
+            self.set_state(State::Idle);
+            self.data.take().and_then(Result::err)
+        } else {
+            None
+        }
+    }
+
+    /// Manually sets the data and moves the state to `Finished`.
+    ///
+    /// This can be used to inject data into the `Bind` without running an async operation.
+    ///
+    /// # Panics
+    /// Panics if the current state is not `Idle`.
+    pub fn fill(&mut self, data: Result<T, E>) {
+        self.poll();
+
+        assert!(
+            matches!(self.state, State::Idle),
+            "Cannot fill a Bind that is not Idle."
+        );
+
+        self.store_filled(data);
+    }
+
+    /// Manually sets a success value and moves the state to `Finished`. See `fill`.
+    ///
+    /// # Panics
+    /// Panics if the current state is not `Idle`.
+    pub fn fill_ok(&mut self, value: T) {
+        self.fill(Ok(value));
+    }
+
+    /// Manually sets an error and moves the state to `Finished`. See `fill`.
+    ///
+    /// # Panics
+    /// Panics if the current state is not `Idle`.
+    pub fn fill_err(&mut self, err: E) {
+        self.fill(Err(err));
+    }
+
+    /// Manually sets the data and moves the state to `Finished`, from any state.
+    ///
+    /// Unlike `fill`, this does not panic: existing data is replaced, and an in-flight
+    /// operation is cancelled as by `cancel`, so its result is never received. The `Bind` goes
+    /// straight to `Finished`, without passing through `Idle`, which suits seeding a `Bind`
+    /// from a cache after it has already run.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn fill_or_replace(&mut self, data: Result<T, E>) {
+        self.cancel();
+        self.store_filled(data);
+    }
+
+    /// Overwrites the stored result with `data` and returns the previous one, keeping the
+    /// state and completion time.
+    ///
+    /// This patches cached data in place, e.g. for an optimistic update before a background
+    /// refresh confirms it. It works while `Finished`, and while `Pending` (the new data is
+    /// then shown until the operation completes). On an `Idle` `Bind` it acts like `fill`
+    /// and returns `None`. Unlike `fill`, it never panics.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn replace_data(&mut self, data: Result<T, E>) -> Option<Result<T, E>> {
+        self.poll();
+
+        if matches!(self.state, State::Idle) {
+            self.store_filled(data);
+            return None;
+        }
+        self.data.replace(data)
+    }
+
+    /// Shows `value` as the result while the in-flight operation is still `Pending`.
+    ///
+    /// This is for optimistic UI: the expected outcome of e.g. a save is presented right away,
+    /// and `is_optimistic` reports that it is not confirmed yet. `read` returns the value, but
+    /// the state stays `Pending`, so `is_pending` still reports the operation. When the real
+    /// result arrives, it replaces the value and clears the flag. If the operation ends without
+    /// a result (it is cancelled, times out or its task is dropped), the value is discarded;
+    /// the data it replaced is not restored.
+    ///
+    /// Returns `false`, leaving the `Bind` untouched, if nothing is `Pending` (e.g. the
+    /// operation already finished).
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.button("Like").clicked() {
+    ///     likes.request(api::like(post_id));
+    ///     likes.optimistic_fill(count + 1);
+    /// }
+    /// ```
+    pub fn optimistic_fill(&mut self, value: T) -> bool {
+        self.poll();
+
+        if !matches!(self.state, State::Pending) {
+            return false;
+        }
+        self.data = Some(Ok(value));
+        self.optimistic = true;
+        true
+    }
+
+    /// Returns `true` if the data is a provisional value set by `optimistic_fill` that the
+    /// in-flight operation has not confirmed yet.
+    pub const fn is_optimistic(&self) -> bool {
+        self.optimistic
+    }
+
+    /// Internal helper that stores manually provided data as a completed result.
+    fn store_filled(&mut self, data: Result<T, E>) {
+        self.set_state(State::Finished);
+        self.last_complete_time = self.now();
+        self.completed_on_frame = self.frame_index();
+        self.data = Some(data);
+        self.refresh_failed = false;
+        self.validator_token = None;
+        self.bump_generation();
+    }
+
+    /// Waits for the in-flight operation to finish and returns its result.
+    ///
+    /// This is intended for orchestration code that runs off the UI thread. If the `Bind` is
+    /// not `Pending`, it returns the currently stored data immediately.
+    ///
+    /// This method does not call `poll()`, so frame timers and `retain` logic are unaffected.
+    ///
+    /// If the task was dropped without sending a result (e.g. it panicked), the `Bind` leaves
+    /// `Pending` as described for `poll`, `try_poll` reports the failure, and this returns
+    /// `None`.
+    pub async fn ready(&mut self) -> Option<&Result<T, E>> {
+        if matches!(self.state, State::Pending)
+            && let Some(rx) = self.recv.as_mut()
+        {
+            let result = rx.await;
+            return self.receive_ready(result);
+        }
+        self.data.as_ref()
+    }
+
+    /// Internal helper for `ready` and `ready_timeout` that takes in an awaited result.
+    fn receive_ready(
+        &mut self,
+        result: Result<Completion<T, E>, oneshot::Canceled>,
+    ) -> Option<&Result<T, E>> {
+        self.recv = None;
+        if let Ok(result) = result {
+            self.receive(result);
+            self.data.as_ref()
+        } else {
+            warn!("Async task's sender was dropped without sending a result.");
+            self.poll_error = Some(PollError::TaskDropped);
+            self.settle_after_abort();
+            None
+        }
+    }
+
+    /// Returns a future that resolves once the current operation is no longer `Pending`.
+    ///
+    /// Unlike `ready`, the future does not borrow the `Bind`, so it can be awaited elsewhere,
+    /// e.g. to chain non-UI logic onto a request started from the UI. It resolves when the
+    /// `Bind` takes in the result (on a `poll`, usually during the next frame), and also if
+    /// the operation is cancelled, superseded by another request, or the `Bind` is dropped.
+    /// If nothing is `Pending`, it resolves immediately. Read the outcome from the `Bind`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// self.upload.request(upload_file(path));
+    /// let done = self.upload.completion_future();
+    /// tokio::spawn(async move {
+    ///     done.await;
+    ///     notify_user("Upload finished").await;
+    /// });
+    /// ```
+    pub fn completion_future(&self) -> impl Future<Output = ()> + MaybeSend + 'static {
+        let rx = self.completion_tx.as_ref().map(watch::Sender::subscribe);
+        async move {
+            if let Some(mut rx) = rx {
+                // Only ever fails, once the sender is dropped.
+                let _ = rx.changed().await;
+            }
+        }
+    }
+
+    /// Like `ready`, but gives up after `timeout` has elapsed.
+    ///
+    /// Returns `None` if the timeout elapses before the operation finishes. This does **not**
+    /// cancel the task or reset the `Bind`: it stays `Pending`, and the result may still arrive
+    /// later and be observed through `poll` (or another call to `ready`).
+    ///
+    /// On native targets this uses the timer of the runtime selected by the `runtime-*` feature;
+    /// with `runtime-tokio` it must be awaited from within a Tokio runtime. On WASM a browser
+    /// timer is used instead.
+    ///
+    /// A task dropped without a result is handled as by `ready`.
+    pub async fn ready_timeout(&mut self, timeout: Duration) -> Option<&Result<T, E>> {
+        if matches!(self.state, State::Pending)
+            && let Some(rx) = self.recv.as_mut()
+        {
+            let result = self::timeout(timeout, rx).await?;
+            return self.receive_ready(result);
+        }
+        self.data.as_ref()
+    }
+
+    /// Fans the finished result out to two destinations: successes are mirrored into
+    /// `data_bind`, errors are routed to `err_sink`.
+    ///
+    /// This suits apps with centralized error display (e.g. a global toast) and distributed
+    /// data rendering. Call it every frame; a new result is only forwarded once, and
+    /// `err_sink` is called exactly once per failed completion. An `Err` is mirrored into
+    /// `data_bind` as `Err(())` so its state still reflects the failure.
+    ///
+    /// The success value is cloned, so `T` must be `Clone`. This is read-only on `self`: it does
+    /// not call `poll()`, so drive the source as usual elsewhere. `data_bind` should be a
+    /// passive mirror that does not run requests of its own.
+    pub fn tee(&self, data_bind: &mut Bind<T, ()>, mut err_sink: impl FnMut(&E))
+    where
+        T: Clone,
+    {
+        if !matches!(self.state, State::Finished) {
+            return;
+        }
+
+        let is_new = data_bind.completions != self.completions;
+        if !is_new && data_bind.data.is_some() {
+            return;
+        }
+
+        match &self.data {
+            Some(Ok(value)) => data_bind.data = Some(Ok(value.clone())),
+            Some(Err(err)) => {
+                if is_new {
+                    err_sink(err);
+                }
+                data_bind.data = Some(Err(()));
+            }
+            None => return,
+        }
+
+        data_bind.set_state(State::Finished);
+        data_bind.last_start_time = self.last_start_time;
+        data_bind.last_complete_time = self.last_complete_time;
+        data_bind.completed_on_frame = self.completed_on_frame;
+        data_bind.completions = self.completions;
+    }
+
+    /// Checks if the current state is `Idle`.
+    /// This method calls `poll()` internally.
+    pub fn is_idle(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, State::Idle)
+    }
+
+    /// Checks if the current state is `Pending`.
+    /// This method calls `poll()` internally.
+    pub fn is_pending(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, State::Pending)
+    }
+
+    /// Checks if the current state is `Finished`.
+    /// This method calls `poll()` internally.
+    pub fn is_finished(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, State::Finished)
+    }
+
+    /// Returns `true` if the operation finished during the current `egui` frame.
+    /// This method calls `poll()` internally.
+    pub fn just_completed(&mut self) -> bool {
+        self.poll();
+        self.completed_on_frame == self.frame_index()
+    }
+
+    /// If the operation just completed this frame, invokes the provided closure with
+    /// a reference to the result.
+    pub fn on_finished(&mut self, f: impl FnOnce(&Result<T, E>)) {
+        if self.just_completed()
+            && let Some(ref d) = self.data
+        {
+            f(d);
+        }
+    }
+
+    /// If the operation just completed successfully this frame, invokes the provided closure
+    /// with a reference to the value.
+    ///
+    /// A failed refresh that kept the previous value (see `request_with_stale_fallback`) does
+    /// not count as a success.
+    pub fn on_success(&mut self, f: impl FnOnce(&T)) {
+        if self.just_completed()
+            && !self.refresh_failed
+            && let Some(Ok(ref value)) = self.data
+        {
+            f(value);
+        }
+    }
+
+    /// If the operation just completed with an error this frame, invokes the provided closure
+    /// with a reference to the error.
+    pub fn on_error(&mut self, f: impl FnOnce(&E)) {
+        if self.just_completed()
+            && let Some(Err(ref err)) = self.data
+        {
+            f(err);
+        }
+    }
+
+    /// Invokes the provided closure on the frame this `Bind` *enters* the given `State`.
+    ///
+    /// This is edge-triggered: `f` is called once per transition into `state`, not on every
+    /// frame the `Bind` merely is in that state. For example, `when(State::Finished, ...)` fires
+    /// exactly once per completion, which is handy for opening a results panel.
+    ///
+    /// Like `just_completed`, the transition is matched against the current frame, so call this
+    /// after any code in the same frame that may start a request.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn when(&mut self, state: State, f: impl FnOnce(StateWithData<'_, T, E>)) {
+        self.poll();
+        if self.state == state && self.state_entered_frame == self.frame_index() {
+            f(self.state());
+        }
+    }
+
+    /// Returns `true` if the operation started during the current `egui` frame.
+    /// This method calls `poll()` internally.
+    pub fn just_started(&mut self) -> bool {
+        self.poll();
+        self.started_on_frame == self.frame_index()
+    }
+
+    /// Gets the `egui` time when the operation started.
+    /// This method calls `poll()` internally.
+    pub fn get_start_time(&mut self) -> f64 {
+        self.poll();
+        self.last_start_time
+    }
+
+    /// Gets the `egui` time when the operation completed.
+    /// This method calls `poll()` internally.
+    pub fn get_complete_time(&mut self) -> f64 {
+        self.poll();
+        self.last_complete_time
+    }
+
+    /// Gets the duration between the start and completion of the operation.
+    /// This method calls `poll()` internally.
+    pub fn get_elapsed(&mut self) -> f64 {
+        self.poll();
+        self.last_complete_time - self.last_start_time
+    }
+
+    /// Gets the time elapsed since the operation started.
+    /// This method calls `poll()` internally.
+    pub fn since_started(&mut self) -> f64 {
+        self.poll();
+        self.now() - self.last_start_time
+    }
+
+    /// Gets the time elapsed since the operation completed.
+    /// This method calls `poll()` internally.
+    pub fn since_completed(&mut self) -> f64 {
+        self.poll();
+        self.now() - self.last_complete_time
+    }
+
+    /// Returns the age, in seconds, of the stored data, or `None` if there is none.
+    ///
+    /// Data kept while a new operation is `Pending` keeps aging until the new result arrives.
+    /// This method calls `poll()` internally.
+    pub fn freshness(&mut self) -> Option<f64> {
+        self.poll();
+        self.data
+            .as_ref()
+            .map(|_| self.now() - self.last_complete_time)
+    }
+
+    /// Returns `true` if there is stored data older than `max_age` seconds.
+    ///
+    /// This includes data kept while a refresh is `Pending`, so a view can dim outdated values
+    /// until the new ones arrive.
+    /// This method calls `poll()` internally.
+    pub fn is_stale(&mut self, max_age: f64) -> bool {
+        self.freshness().is_some_and(|age| age > max_age)
+    }
+
+    /// Drops the data and returns to `Idle` if the `Bind` is `Finished` with a result older
+    /// than `ttl` seconds. Returns `true` if it did.
+    ///
+    /// Call this every frame to evict cached data after a time to live, so that the next
+    /// access (e.g. `read_or_request`) fetches it again. Unlike `retain`, this depends on the
+    /// age of the data, not on whether it is drawn. Data kept while a refresh is `Pending` is
+    /// left alone.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn clear_if_stale(&mut self, ttl: f64) -> bool {
+        self.poll();
+
+        if matches!(self.state, State::Finished) && self.since_completed() > ttl {
+            self.reset_to_idle();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an immutable reference to the stored data, if any.
+    /// This method calls `poll()` internally.
+    pub fn read(&mut self) -> &Option<Result<T, E>> {
+        self.poll();
+        &self.data
+    }
+    /// Returns an immutable reference in the ref pattern to the stored data, if any.
+    /// This method calls `poll()` internally.
+    pub fn read_as_ref(&mut self) -> Option<Result<&T, &E>> {
+        self.poll();
+        self.data.as_ref().map(Result::as_ref)
+    }
+
+    /// Returns a mutable reference to the stored data, if any.
+    /// This method calls `poll()` internally.
+    pub fn read_mut(&mut self) -> &mut Option<Result<T, E>> {
+        self.poll();
+        &mut self.data
+    }
+    /// Returns a mutable reference in the ref pattern to the stored data, if any.
+    /// This method calls `poll()` internally.
+    pub fn read_as_mut(&mut self) -> Option<Result<&mut T, &mut E>> {
+        self.poll();
+        self.data.as_mut().map(Result::as_mut)
+    }
+
+    /// Returns a reference to the success value, if the data is `Ok`.
+    /// This method calls `poll()` internally.
+    pub fn ok(&mut self) -> Option<&T> {
+        self.poll();
+        self.data.as_ref().and_then(|data| data.as_ref().ok())
+    }
+
+    /// Returns a reference to the error, if the data is `Err`.
+    /// This method calls `poll()` internally.
+    pub fn err(&mut self) -> Option<&E> {
+        self.poll();
+        self.data.as_ref().and_then(|data| data.as_ref().err())
+    }
+
+    /// Returns a mutable reference to the success value, if the data is `Ok`.
+    /// This method calls `poll()` internally.
+    pub fn ok_mut(&mut self) -> Option<&mut T> {
+        self.poll();
+        self.data.as_mut().and_then(|data| data.as_mut().ok())
+    }
+
+    /// Returns a mutable reference to the error, if the data is `Err`.
+    /// This method calls `poll()` internally.
+    pub fn err_mut(&mut self) -> Option<&mut E> {
+        self.poll();
+        self.data.as_mut().and_then(|data| data.as_mut().err())
+    }
+
+    /// Returns the current `State` of the binding.
+    /// This method calls `poll()` internally.
+    pub fn get_state(&mut self) -> State {
+        self.poll();
+        self.state
+    }
+
+    /// Returns the current `State` without polling.
+    ///
+    /// Unlike `get_state`, this neither picks up a finished result nor counts as drawing the
+    /// `Bind`, so it is safe to use from logging or a secondary view.
+    pub const fn peek_state(&self) -> State {
+        self.state
+    }
+
+    /// Returns the stored data without polling. See `peek_state`.
+    pub const fn peek_data(&self) -> &Option<Result<T, E>> {
+        &self.data
+    }
+
+    /// Returns the stored result without polling, e.g. from a `&self` render or `Debug` helper.
+    ///
+    /// Like `peek_data`, this never mutates the `Bind`: it does not pick up a finished result,
+    /// count as drawing, or trigger the `retain = false` clearing.
+    pub const fn read_cached(&self) -> Option<Result<&T, &E>> {
+        match &self.data {
+            Some(result) => Some(result.as_ref()),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if the state is `Pending`, without polling. See `peek_state`.
+    pub const fn peek_is_pending(&self) -> bool {
+        matches!(self.state, State::Pending)
+    }
+
+    /// Returns the ref filled state of the `Bind`, allowing for exhaustive pattern matching.
+    ///
+    /// This is often the most ergonomic way to display UI based on the `Bind`'s state.
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match my_bind.state() {
+    ///     StateWithData::Idle => { /* ... */ }
+    ///     StateWithData::Pending => { ui.spinner(); }
+    ///     StateWithData::Finished(data) => { ui.label(format!("Data: {data:?}")); }
+    ///     StateWithData::Failed(err) => { ui.label(format!("Error: {err:?}")); }
+    /// }
+    /// ```
+    pub fn state(&mut self) -> StateWithData<'_, T, E> {
+        self.poll();
+        match self.state {
+            State::Idle => StateWithData::Idle,
+            State::Pending => StateWithData::Pending,
+            State::Finished => match self.data.as_ref() {
+                Some(Ok(data)) => StateWithData::Finished(data),
+                Some(Err(err)) => StateWithData::Failed(err),
+                None => {
+                    // This case should be unreachable due to internal invariants.
+                    // If state is Finished, data must be Some.
+                    self.state = State::Idle;
+                    StateWithData::Idle
+                }
+            },
+        }
+    }
+
+    /// Returns an owned copy of the current state, cloning the data.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn snapshot(&mut self) -> StateSnapshot<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        self.state().into()
+    }
+
+    /// Returns the ref filled state or starts a new request if idle.
+    ///
+    /// This method is an ergonomic way to drive a UI. If the `Bind` is `Idle` and has no
+    /// data, it immediately calls the provided closure `f` to start an async operation,
+    /// transitioning the state to `Pending`.
+    ///
+    /// In all cases, it returns the current `StateWithData` for immediate use in a `match`
+    /// statement, making it easy to display a loading indicator, the finished data, or an error.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // In your UI update function:
+    /// match my_bind.state_or_request(fetch_data) {
+    ///     StateWithData::Idle => { /* This branch is typically not reached on the first call */ }
+    ///     StateWithData::Pending => { ui.spinner(); }
+    ///     StateWithData::Finished(data) => { ui.label(format!("Data: {:?}", data)); }
+    ///     StateWithData::Failed(err) => { ui.label(format!("Error: {:?}", err)); }
+    /// }
+    /// ```
+    pub fn state_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> StateWithData<'_, T, E>
     where
         Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
         T: MaybeSend,
         E: MaybeSend,
     {
-        #[cfg(not(target_family = "wasm"))]
+        self.poll();
+
+        if self.data.is_none() && matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+        }
+        self.state()
+    }
+
+    /// Starts a request with `f` if `cond` holds and the `Bind` is `Idle` without data.
+    ///
+    /// This gates the "start if idle" behavior of `read_or_request` on an app condition, such
+    /// as the user being logged in or a panel being open. Returns the resulting `State`.
+    ///
+    /// This method calls `poll()` internally.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if self.profile.request_if(self.session.is_some(), fetch_profile) == State::Pending {
+    ///     ui.spinner();
+    /// }
+    /// ```
+    pub fn request_if<Fut>(&mut self, cond: bool, f: impl FnOnce() -> Fut) -> State
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if cond && self.data.is_none() && matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+        }
+        self.state
+    }
+
+    /// Like `state_or_request`, but also retries a failed operation after a cooldown.
+    ///
+    /// If the `Bind` is `Idle` without data, `f(false)` starts the first request. If the latest
+    /// operation failed and more than `cooldown` seconds have passed since it completed,
+    /// `f(true)` retries it; the flag lets the closure react to the retry, e.g. by bypassing a
+    /// cache. The cooldown keeps a persistent failure from turning into a tight retry loop,
+    /// while transient failures still recover on their own.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match my_bind.state_or_request_retry(|retry| fetch_data(retry), 5.0) {
+    ///     StateWithData::Idle | StateWithData::Pending => { ui.spinner(); }
+    ///     StateWithData::Finished(data) => { ui.label(format!("Data: {data:?}")); }
+    ///     StateWithData::Failed(err) => { ui.label(format!("Error: {err:?}, retrying…")); }
+    /// }
+    /// ```
+    pub fn state_or_request_retry<Fut>(
+        &mut self,
+        f: impl FnOnce(bool) -> Fut,
+        cooldown: f64,
+    ) -> StateWithData<'_, T, E>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if self.data.is_none() && matches!(self.state, State::Idle) {
+            self.request_if_online(|| f(false));
+        } else if matches!(self.state, State::Finished) && matches!(self.data, Some(Err(_))) {
+            let remaining = cooldown - (self.now() - self.last_complete_time);
+            if remaining < 0.0 {
+                self.request_if_online(|| f(true));
+            } else {
+                // Make sure a frame happens when the cooldown is over.
+                #[cfg(feature = "egui")]
+                if let Some(ctx) = self.ctx() {
+                    #[allow(clippy::cast_possible_truncation)]
+                    ctx.request_repaint_after_secs(remaining as f32);
+                }
+            }
+        }
+        self.state()
+    }
+
+    /// Drives the `Bind` for this frame and reports what happened, starting a request if idle.
+    ///
+    /// This combines `state_or_request` with the `just_started` and `just_completed` edges in
+    /// a single call:
+    /// - `Step::Started` is returned only by the call that starts the request.
+    /// - `Step::JustCompleted` is returned by every call during the frame in which the result
+    ///   was received; after that, `Step::Finished` is returned.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match self.save.step(|| save_document(doc.clone())) {
+    ///     Step::JustCompleted(Ok(())) => toasts.success("Saved!"),
+    ///     Step::Started | Step::Pending => { ui.spinner(); }
+    ///     Step::JustCompleted(Err(err)) | Step::Finished(Err(err)) => { ui.label(err); }
+    ///     Step::Idle | Step::Finished(Ok(())) => {}
+    /// }
+    /// ```
+    pub fn step<Fut>(&mut self, f: impl FnOnce() -> Fut) -> Step<'_, T, E>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if matches!(self.state, State::Idle) {
+            return if self.request_if_online(f) {
+                Step::Started
+            } else {
+                Step::Idle
+            };
+        }
+
+        let just_completed = self.completed_on_frame == self.frame_index();
+        match (&self.state, &self.data) {
+            (State::Finished, Some(result)) if just_completed => Step::JustCompleted(result),
+            (State::Finished, Some(result)) => Step::Finished(result),
+            (State::Idle, _) => Step::Idle,
+            (State::Pending | State::Finished, _) => Step::Pending,
+        }
+    }
+
+    /// Clears any stored data and resets the state to `Idle`.
+    ///
+    /// If an operation was `Pending`, its result will be discarded. The background task is not
+    /// cancelled and will run to completion, unless `set_cancel_on_clear` is enabled.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn clear(&mut self) {
+        self.poll();
+        if self.cancel_on_clear {
+            self.abort_task();
+        }
+        self.reset_to_idle();
+        self.paced = None;
+        self.debounced = None;
+    }
+
+    /// Returns the `Bind` to the state it had when it was created, as if freshly built.
+    ///
+    /// Unlike `clear`, this also forgets the timestamps (so `since_completed` is large again
+    /// and periodic requests fire at once), the execution count and statistics, the timing
+    /// history, and the keys and tokens of earlier requests. An in-flight operation is
+    /// cancelled. This suits reusing a `Bind` for a new session, e.g. after the user logs out.
+    ///
+    /// Configuration is kept: the retain policy, the settings of `BindBuilder`, the name, the
+    /// history capacity, the minimum error display time, the auto-clear delay and the
+    /// backpressure mode. Existing subscriptions stay connected.
+    pub fn reset(&mut self) {
+        self.abort_task();
+
+        let mut fresh = Self::new(self.retain);
+        fresh.history_capacity = self.history_capacity;
+        fresh.min_error_display = self.min_error_display;
+        fresh.generation = self.generation.take();
+        fresh.auto_clear = self.auto_clear;
+        fresh.backpressure = self.backpressure;
+        fresh.cancel_on_clear = self.cancel_on_clear;
+        fresh.default_timeout = self.default_timeout;
+        fresh.min_pending = self.min_pending;
+        fresh.completions = self.completions;
+        #[cfg(all(not(target_family = "wasm"), feature = "runtime-tokio"))]
+        {
+            fresh.runtime = self.runtime.take();
+        }
+        #[cfg(feature = "egui")]
+        {
+            fresh.scope = self.scope.take();
+        }
+        fresh.name = self.name.take();
+
+        *self = fresh;
+    }
+
+    /// Returns a reference to the data, or starts a new request if idle.
+    ///
+    /// If data is already available (`Finished`), it returns a reference to it.
+    /// If the state is `Idle` and no data is present, it calls `f` to start a new async
+    /// operation and returns `None`.
+    /// If `Pending`, it returns `None`.
+    ///
+    /// `f` may capture borrowed app state, as long as the future it returns owns everything
+    /// it needs. Clone the inputs inside `f`, before the `async` block:
+    /// ```ignore
+    /// self.user.read_or_request(|| {
+    ///     let (client, id) = (self.client.clone(), self.selected);
+    ///     async move { fetch_user(client, id).await }
+    /// });
+    /// ```
+    /// See also `request_with_args`.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn read_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> Option<&Result<T, E>>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if self.data.is_none() && matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+        }
+        self.data.as_ref()
+    }
+
+    /// Returns a mutable reference to the data, or starts a new request if idle.
+    ///
+    /// This is the mutable version of `read_or_request`.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn read_mut_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> Option<&mut Result<T, E>>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if self.data.is_none() && matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+        }
+        self.data.as_mut()
+    }
+
+    /// Drives the state machine. This should be called once per frame before accessing state.
+    ///
+    /// **Note**: Most other methods on `Bind` call this internally, so you usually don't
+    /// need to call it yourself.
+    ///
+    /// This method performs several key actions:
+    /// 1. Checks if a pending future has completed and, if so, updates the state to `Finished`.
+    /// 2. Updates internal frame timers used for `retain` logic and time tracking.
+    /// 3. If `retain` is `false`, it clears the data if the `Bind` was not polled in the previous frame.
+    /// 4. Starts a request queued by `request_paced` once its slot has come up.
+    /// 5. Picks up the latest progress reported to `request_with_progress`.
+    ///
+    /// If the operation ends without a result, e.g. because its task panicked, the `Bind`
+    /// leaves `Pending` (see `cancel`) and a warning is logged. Use `try_poll` to handle this.
+    pub fn poll(&mut self) {
+        self.poll_at(self.frame_time());
+    }
+
+    /// Polls every `Bind` in `binds`, reading the global frame clock only once.
+    ///
+    /// This is `poll` for screens with many `Bind`s of the same type, such as the rows of a
+    /// large table. `Bind`s attached to a context with `with_ctx` read that context's clock.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut binds: Vec<_> = self.rows.iter_mut().map(|row| &mut row.status).collect();
+    /// Bind::poll_many(&mut binds);
+    /// ```
+    pub fn poll_many(binds: &mut [&mut Self]) {
+        let global = FrameTime::global();
+        for bind in binds {
+            #[cfg(feature = "egui")]
+            let frame = if bind.scope.is_some() {
+                bind.frame_time()
+            } else {
+                global
+            };
+            #[cfg(not(feature = "egui"))]
+            let frame = global;
+
+            bind.poll_at(frame);
+        }
+    }
+
+    /// Internal helper for `poll` and `poll_many` that polls at the given frame time.
+    fn poll_at(&mut self, frame: FrameTime) {
+        let curr_frame = frame.now;
+
+        // Avoid re-polling within the same frame. Without a driven frame clock there are no
+        // frames to tell apart, so every poll counts as a new one.
+        if frame.index > 0 && frame.index == self.drawn_on_frame {
+            return;
+        }
+
+        // If `retain` is false and the UI element associated with this `Bind` was not rendered
+        // in the previous frame, we clear its data to free resources and ensure a fresh load.
+        // Without a driven frame clock there are no frames to miss, so nothing is cleared.
+        let skipped_frame = frame.index.saturating_sub(self.drawn_on_frame) > 1;
+
+        // Shift frame times for tracking visibility across frames.
+        self.drawn_time_prev = self.drawn_time_last;
+        self.drawn_time_last = curr_frame;
+        self.drawn_on_frame_prev = self.drawn_on_frame;
+        self.drawn_on_frame = frame.index;
+
+        if !self.retain && frame.index > 0 && skipped_frame {
+            // Manually clear state to avoid a recursive call to poll() from clear().
+            self.reset_to_idle();
+        }
+
+        // Expire an ephemeral result, or make sure a frame is drawn when it is due.
+        if let Some(secs) = self.auto_clear
+            && matches!(self.state, State::Finished)
+        {
+            let remaining = secs - (curr_frame - self.last_complete_time);
+            if remaining < 0.0 {
+                self.reset_to_idle();
+            } else {
+                #[cfg(feature = "egui")]
+                if let Some(ctx) = self.ctx() {
+                    #[allow(clippy::cast_possible_truncation)]
+                    ctx.request_repaint_after_secs(remaining as f32);
+                }
+            }
+        }
+
+        // Deliver a mocked result once its frame-clock delay has passed.
+        #[cfg(feature = "test-util")]
+        if self
+            .mock
+            .as_ref()
+            .is_some_and(|mock| mock.ready_at <= curr_frame)
+            && let Some(mock) = self.mock.take()
+        {
+            let _ = mock.tx.send(Completion::Result(mock.result));
+        }
+
+        // Before the result, so a final progress update is not missed.
+        self.receive_progress();
+
+        // Live sources deliver through their own channel instead of `recv`.
+        if matches!(self.state, State::Pending)
+            && self.live.is_none()
+            && !self.hold_pending(curr_frame)
+        {
+            self.try_receive();
+        }
+
+        // Pick up the outcome of a `request_local_first` sync.
+        if let Some(rx) = &mut self.sync_recv {
+            match rx.try_recv() {
+                Ok(Some(Ok(()))) => {
+                    self.sync_status = Some(SyncStatus::Synced);
+                    self.sync_recv = None;
+                    self.successes += 1;
+                }
+                Ok(Some(Err(err))) => {
+                    self.sync_status = Some(SyncStatus::Failed);
+                    self.sync_error = Some(err);
+                    self.sync_recv = None;
+                    self.failures += 1;
+                }
+                Ok(None) => {}
+                Err(oneshot::Canceled) => {
+                    warn!("Sync task was dropped without sending a result.");
+                    self.sync_status = Some(SyncStatus::Failed);
+                    self.sync_recv = None;
+                    self.failures += 1;
+                }
+            }
+        }
+
+        // Start a request queued by a pacer or a debounce window once it is due.
+        self.start_queued(curr_frame);
+
+        // Present a value from a live source, as chosen by its `BackpressureMode`.
+        if let Some(result) = self.live.as_mut().and_then(LiveFeed::next) {
+            self.complete(result);
+        }
+    }
+
+    /// Polls, and returns the new state if this call changed it (e.g. `Pending` to `Finished`).
+    ///
+    /// Returns `None` if the state did not change, including on any further call within the
+    /// same frame, since `poll` runs at most once per frame. This makes it a reliable edge
+    /// detector, e.g. for playing a sound exactly once when a request completes.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if bind.poll_and_report() == Some(State::Finished) {
+    ///     play_sound("done.wav");
+    /// }
+    /// ```
+    pub fn poll_and_report(&mut self) -> Option<State> {
+        let before = self.state;
+        self.poll();
+        (self.state != before).then_some(self.state)
+    }
+
+    /// Like `poll`, but returns an error if the operation ended without a result.
+    ///
+    /// The error is reported once, by the first `try_poll` after it happened, even if that
+    /// call is in a later frame. `poll` and every other method recover from such a failure
+    /// the same way, but only log it.
+    ///
+    /// # Errors
+    /// Returns a `PollError` if the background task was dropped without sending a result,
+    /// e.g. because it panicked.
+    pub fn try_poll(&mut self) -> Result<(), PollError> {
+        self.poll();
+        self.poll_error.take().map_or(Ok(()), Err)
+    }
+
+    /// Drives the `Bind` until its operation finishes or `timeout` elapses, and returns the
+    /// result, if there is one.
+    ///
+    /// This is meant for tests: it polls in a loop until the result arrives, so a test can
+    /// assert on the outcome of a request without simulating `egui` frames by hand. Returns
+    /// `None` if nothing was requested or the operation did not finish in time.
+    ///
+    /// If the `Bind` is attached to a context (see `BindBuilder::context`), every iteration
+    /// advances that context's frame clock in real time, so delays measured on the frame clock
+    /// (a mocked response's `after`, or `BindBuilder::min_pending_duration`) elapse as they
+    /// would in a running app. Only `Bind`s attached to the same context see these frames.
+    ///
+    /// The global frame clock, which every other `Bind` follows, is never advanced. For a
+    /// `Bind` that is not attached to a context, the result is taken in as soon as the task
+    /// sends it, so such delays do not elapse here and the minimum pending time is not applied.
+    ///
+    /// This blocks the calling thread, so it must not be called from a UI thread, nor from
+    /// inside a single-threaded runtime that the `Bind`'s task needs to run on. It is not
+    /// available on WASM.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut bind = Bind::<u32, String>::new(false);
+    /// bind.request(async { Ok(42) });
+    /// assert_eq!(bind.block_until_finished(Duration::from_secs(1)), Some(&Ok(42)));
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn block_until_finished(&mut self, timeout: Duration) -> Option<&Result<T, E>> {
+        let start = std::time::Instant::now();
+
+        // The clock of the attached context, and its time when this started.
+        #[cfg(feature = "egui")]
+        let clock = self
+            .scope
+            .as_ref()
+            .map(|scope| (scope.clock.clone(), scope.clock.now()));
+        #[cfg(feature = "egui")]
+        let attached = clock.is_some();
+        #[cfg(not(feature = "egui"))]
+        let attached = false;
+
+        loop {
+            // Polling starts queued requests and delivers mocked results, but only once per
+            // frame. Without frames of its own, the result itself is taken in directly.
+            self.poll();
+            if !attached && matches!(self.state, State::Pending) && self.live.is_none() {
+                self.try_receive();
+            }
+            if self.state != State::Pending || start.elapsed() >= timeout {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+
+            #[cfg(feature = "egui")]
+            if let Some((clock, started_at)) = &clock {
+                clock.tick(started_at + start.elapsed().as_secs_f64());
+            }
+        }
+
+        if self.state == State::Finished {
+            self.data.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Internal helper that starts a request queued by `request_paced` or `request_debounced`
+    /// once it is due.
+    fn start_queued(&mut self, curr_frame: f64) {
+        // Start a request queued by `request_paced` once its slot has come up.
+        if self
+            .paced
+            .as_ref()
+            .is_some_and(|paced| paced.slot <= curr_frame)
+            && let Some(paced) = self.paced.take()
+        {
+            self.begin_request();
+            self.arm_timeout();
+            self.spawn_task(
+                paced
+                    .task
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner),
+            );
+            self.recv = Some(paced.recv);
+        }
+
+        // Start the trailing request of a debounced burst once its window has passed. It
+        // supersedes the burst's first request, as with `search_latest`.
+        if self
+            .debounced
+            .as_ref()
+            .is_some_and(|debounced| debounced.start_at <= curr_frame)
+            && let Some(debounced) = self.debounced.take()
         {
-            let (tx, rx) = self.prepare_channel();
-            ASYNC_RUNTIME.spawn(Self::req_inner(f, tx));
-            self.recv = Some(rx);
+            self.abort_task();
+            self.begin_request();
+            self.arm_timeout();
+            self.spawn_task(
+                debounced
+                    .task
+                    .into_inner()
+                    .unwrap_or_else(PoisonError::into_inner),
+            );
+            self.recv = Some(debounced.recv);
+            self.abort = Some(debounced.abort);
+        }
+    }
+
+    /// Internal helper that takes in the result of the current operation, if it has arrived.
+    fn try_receive(&mut self) {
+        match self.recv.as_mut().map(oneshot::Receiver::try_recv) {
+            Some(Ok(Some(result))) => {
+                self.receive(result);
+                self.recv = None; // Drop the receiver as it's no longer needed.
+            }
+            Some(Ok(None)) => {
+                // Future is still running, do nothing.
+            }
+            Some(Err(oneshot::Canceled)) => {
+                // The task's sender was dropped without sending a value, e.g. because the
+                // task was aborted, panicked, or the runtime shut down.
+                warn!("Async task's sender was dropped without sending a result.");
+                self.recv = None;
+                self.poll_error = Some(PollError::TaskDropped);
+                self.settle_after_abort();
+            }
+            None => {
+                warn!("BUG: State is Pending but receiver is missing.");
+                self.poll_error = Some(PollError::ReceiverMissing);
+                self.settle_after_abort();
+            }
+        }
+    }
+
+    /// Internal helper that returns `true` while the result of the current operation is held
+    /// back for `BindBuilder::min_pending_duration`, making sure a frame is drawn once it is due.
+    fn hold_pending(&self, curr_frame: f64) -> bool {
+        let remaining = self.min_pending - (curr_frame - self.last_start_time);
+        if remaining <= 0.0 {
+            return false;
+        }
+
+        #[cfg(feature = "egui")]
+        if let Some(ctx) = self.ctx() {
+            #[allow(clippy::cast_possible_truncation)]
+            ctx.request_repaint_after_secs(remaining as f32);
+        }
+        true
+    }
+
+    /// Internal helper that keeps the latest value reported through a `ProgressSender`.
+    fn receive_progress(&mut self) {
+        let Some(rx) = &mut self.progress_recv else {
+            return;
+        };
+
+        loop {
+            match rx.try_recv() {
+                Ok(progress) => self.progress = Some(progress),
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.progress_recv = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Internal helper that drops the data (and any live source) and returns to `Idle`.
+    fn reset_to_idle(&mut self) {
+        self.set_state(State::Idle);
+        self.data = None;
+        self.refresh_failed = false;
+        self.validator_token = None;
+        self.live = None;
+        self.operation_key = None;
+        self.sync_recv = None;
+        self.sync_status = None;
+        self.sync_error = None;
+        self.deps_hash = None;
+        self.last_key = None;
+        self.progress_recv = None;
+        self.progress = None;
+    }
+
+    /// Internal helper that changes the state, recording when a new state was entered.
+    fn set_state(&mut self, state: State) {
+        if self.state != state {
+            if state != State::Pending {
+                self.completion_tx = None;
+                self.optimistic = false;
+            }
+            self.state = state;
+            self.state_entered_time = self.now();
+            self.state_entered_frame = self.frame_index();
         }
+    }
 
-        #[cfg(target_family = "wasm")]
+    /// Internal helper that handles a message received from a background task.
+    fn receive(&mut self, completion: Completion<T, E>) {
+        match completion {
+            Completion::Result(result) => self.complete(result),
+            Completion::TimedOut => {
+                warn!("Request was dropped after exceeding its timeout.");
+                self.timed_out = true;
+                self.operation_key = None;
+                self.settle_after_abort();
+            }
+            Completion::Conditional(Err(err)) => self.complete(Err(err)),
+            Completion::Conditional(Ok(CondResult::Modified(data, token))) => {
+                self.complete(Ok(data));
+                self.validator_token = token;
+            }
+            Completion::Conditional(Ok(CondResult::NotModified)) => {
+                self.discard_optimistic();
+                if self.data.is_some() {
+                    self.refresh_failed = false;
+                    self.stale_fallback = false;
+                    self.finish();
+                } else {
+                    warn!("Conditional request was not modified, but there is no data to keep.");
+                    self.set_state(State::Idle);
+                }
+            }
+        }
+    }
+
+    /// Internal helper that stores the result of a finished operation and moves to `Finished`.
+    fn complete(&mut self, result: Result<T, E>) {
+        if self.stale_fallback
+            && result.is_err()
+            && !self.optimistic
+            && matches!(self.data, Some(Ok(_)))
         {
-            let (tx, rx) = self.prepare_channel();
-            wasm_bindgen_futures::spawn_local(Self::req_inner(f, tx));
-            self.recv = Some(rx);
+            // Keep presenting the last good value, but remember that the refresh failed.
+            self.refresh_failed = true;
+        } else {
+            self.refresh_failed = false;
+            self.data = Some(result);
+            // New data from a regular request is not covered by the old validator.
+            self.validator_token = None;
+        }
+        self.stale_fallback = false;
+        self.finish();
+    }
+
+    /// Internal helper that counts a new completion and notifies subscriptions of it.
+    fn bump_generation(&mut self) {
+        self.completions += 1;
+        if let Some(generation) = &self.generation {
+            generation.fetch_add(1, Ordering::Release);
         }
+    }
 
-        self.times_executed += 1;
+    /// Internal helper that records the completion of an operation and moves to `Finished`.
+    fn finish(&mut self) {
+        self.operation_key = None;
+        self.last_complete_time = self.now();
+        self.completed_on_frame = self.frame_index();
+        self.set_state(State::Finished);
+        self.bump_generation();
+
+        let succeeded = matches!(self.data, Some(Ok(_))) && !self.refresh_failed;
+        if succeeded {
+            self.successes += 1;
+        } else {
+            self.failures += 1;
+        }
+
+        if self.history_capacity > 0 {
+            if self.history.len() == self.history_capacity {
+                self.history.pop_front();
+                self.latencies.remove(0);
+            }
+            self.history.push_back(RequestTiming {
+                start_time: self.last_start_time,
+                complete_time: self.last_complete_time,
+                succeeded,
+            });
+            self.latencies
+                .push(self.last_complete_time - self.last_start_time);
+        }
     }
 
-    /// Requests an operation to run periodically.
+    /// Returns the current retain policy. See `new` and `set_retain`.
+    pub const fn retain(&self) -> bool {
+        self.retain
+    }
+
+    /// Changes the retain policy at runtime.
     ///
-    /// If the `Bind` is not `Pending` and more than `secs` seconds have passed since the
-    /// last completion, a new request is started by calling `f`.
+    /// Switching to `true` takes effect immediately: data is kept even if a frame was already
+    /// skipped. Switching to `false` never clears data retroactively; the "cleared if not
+    /// drawn in the previous frame" rule applies from the next frame on.
+    pub fn set_retain(&mut self, retain: bool) {
+        if self.retain && !retain && !self.was_drawn_this_frame() {
+            // Pretend the previous frame was drawn, so a skipped frame while retained does
+            // not count against the new policy.
+            self.drawn_time_last = self.last_frame();
+            self.drawn_on_frame_prev = self.drawn_on_frame;
+            self.drawn_on_frame = self.frame_index().saturating_sub(1);
+        }
+        self.retain = retain;
+    }
+
+    /// Marks this `Bind` as drawn in the current frame without polling it.
     ///
-    /// # Returns
-    /// The time in seconds remaining until the next scheduled refresh. A negative value
-    /// indicates a refresh is overdue.
-    pub fn request_every_sec<Fut>(&mut self, f: impl FnOnce() -> Fut, secs: f64) -> f64
-    where
-        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
-        T: MaybeSend,
-        E: MaybeSend,
-    {
-        let since_completed = self.since_completed();
+    /// A non-retaining `Bind` clears its data when it was not polled in the previous frame.
+    /// Code that uses the data outside of the UI (and so never calls a polling accessor) can
+    /// call this every frame to keep the data alive.
+    ///
+    /// Unlike `poll`, this does not check for a finished operation or apply the `retain`
+    /// logic; it only records the frame. Since a `Bind` is polled at most once per frame,
+    /// a `poll` later in the same frame is skipped and a finished result is picked up on the
+    /// next frame instead.
+    pub fn mark_drawn(&mut self) {
+        let frame = self.frame_time();
 
-        if self.get_state() != State::Pending && since_completed > secs {
-            self.request(f());
+        if frame.index == 0 || frame.index != self.drawn_on_frame {
+            self.drawn_time_prev = self.drawn_time_last;
+            self.drawn_time_last = frame.now;
+            self.drawn_on_frame_prev = self.drawn_on_frame;
+            self.drawn_on_frame = frame.index;
         }
+    }
 
-        secs - since_completed
+    /// Checks if this `Bind` has been polled during the current `egui` frame.
+    pub fn was_drawn_this_frame(&self) -> bool {
+        self.drawn_on_frame == self.frame_index()
     }
 
-    /// Clears any existing data and immediately starts a new async operation.
+    /// Checks if this `Bind` was polled during the previous `egui` frame.
+    pub fn was_drawn_last_frame(&self) -> bool {
+        self.drawn_on_frame_prev == self.frame_index().saturating_sub(1)
+    }
+
+    /// Returns how many frames have started since this `Bind` was last polled (or marked
+    /// with `mark_drawn`): `0` if it was polled in the current frame, `1` if in the previous
+    /// one. A `Bind` that was never polled counts from the first frame.
     ///
-    /// If an operation was `Pending`, its result will be discarded. The background task is not
-    /// cancelled and will run to completion.
+    /// This is the predicate behind the `retain` logic: a `Bind` that does not retain its
+    /// data clears it when it is polled while this is greater than `1`, i.e. after at least
+    /// one frame in which it was not polled. Unlike the frame times, it does not depend on
+    /// `egui`'s clock, so it is easy to assert on in a test driven by `tick`.
     ///
-    /// This is a convenience method equivalent to calling `clear()` followed by `request()`.
-    pub fn refresh<Fut>(&mut self, f: Fut)
-    where
-        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
-        T: MaybeSend,
-        E: MaybeSend,
-    {
-        self.clear();
-        self.request(f);
+    /// Unlike most accessors, this does not poll.
+    pub fn frames_since_drawn(&self) -> u64 {
+        self.frame_index().saturating_sub(self.drawn_on_frame)
     }
 
-    /// Takes ownership of the result if the operation is `Finished`.
+    /// Returns the `egui` time of the frame in which this `Bind` was last polled.
     ///
-    /// If the state is `Finished`, this method returns `Some(result)`, consumes the data
-    /// internally, and resets the state to `Idle`. If the state is not `Finished`,
-    /// it returns `None`.
+    /// Unlike most accessors, this does not poll.
+    pub const fn last_drawn_time(&self) -> f64 {
+        self.drawn_time_last
+    }
+
+    /// Returns the `egui` time of the frame in which this `Bind` was polled before the last
+    /// one.
     ///
-    /// This method calls `poll()` internally.
-    pub fn take(&mut self) -> Option<Result<T, E>> {
-        self.poll();
+    /// Unlike most accessors, this does not poll.
+    pub const fn prev_drawn_time(&self) -> f64 {
+        self.drawn_time_prev
+    }
 
-        if matches!(self.state, State::Finished) {
-            assert!(
-                self.data.is_some(),
-                "State was Finished but data was None. This indicates a bug."
-            );
-            self.state = State::Idle;
-            self.data.take()
-        } else {
-            None
+    /// Returns all frame, start and completion times of this `Bind` at once, for debugging.
+    ///
+    /// Unlike most accessors, this does not poll.
+    pub fn debug_timing(&self) -> TimingInfo {
+        TimingInfo {
+            frame_time: self.now(),
+            last_frame_time: self.last_frame(),
+            last_drawn_time: self.drawn_time_last,
+            prev_drawn_time: self.drawn_time_prev,
+            state_entered_time: self.state_entered_time,
+            start_time: self.last_start_time,
+            complete_time: self.last_complete_time,
         }
     }
 
-    /// Manually sets the data and moves the state to `Finished`.
+    /// Sets how many completed operations are kept in the timing history.
     ///
-    /// This can be used to inject data into the `Bind` without running an async operation.
+    /// Recording is disabled by default (`0`), so the common case allocates nothing.
+    /// Shrinking the capacity drops the oldest entries.
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.history_capacity = capacity;
+        while self.history.len() > capacity {
+            self.history.pop_front();
+        }
+        let excess = self.latencies.len().saturating_sub(capacity);
+        self.latencies.drain(..excess);
+    }
+
+    /// Returns the timings of the most recently completed operations, oldest first.
     ///
-    /// # Panics
-    /// Panics if the current state is not `Idle`.
-    pub fn fill(&mut self, data: Result<T, E>) {
-        self.poll();
+    /// This is empty unless enabled with `set_history_capacity`.
+    pub const fn history(&self) -> &VecDeque<RequestTiming> {
+        &self.history
+    }
 
-        assert!(
-            matches!(self.state, State::Idle),
-            "Cannot fill a Bind that is not Idle."
-        );
+    /// Returns the durations, in seconds, of the most recently completed operations, oldest
+    /// first, e.g. to plot a latency sparkline.
+    ///
+    /// Each duration is the `get_elapsed` of an operation, recorded along with the timing
+    /// history, so this is empty unless enabled with `set_history_capacity` or
+    /// `BindBuilder::history_capacity`.
+    pub fn latency_history(&self) -> &[f64] {
+        &self.latencies
+    }
 
-        self.state = State::Finished;
-        self.last_complete_time = CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed);
-        self.data = Some(data);
+    /// Returns `true` if an operation has ever finished (or data was filled in), as of the last
+    /// poll.
+    ///
+    /// This stays `true` after the data is dropped, e.g. when a `Bind` that does not retain its
+    /// data returns to `Idle`, so an empty state can tell "not loaded yet" apart from "click to
+    /// load again". Only `reset` makes it `false` again.
+    pub const fn has_ever_finished(&self) -> bool {
+        self.completed_on_frame != u64::MAX
     }
 
-    /// Checks if the current state is `Idle`.
-    /// This method calls `poll()` internally.
-    pub fn is_idle(&mut self) -> bool {
-        self.poll();
-        matches!(self.state, State::Idle)
+    /// Returns the total number of times an async operation has been executed.
+    pub const fn count_executed(&self) -> usize {
+        self.times_executed
     }
 
-    /// Checks if the current state is `Pending`.
+    /// Returns how many operations completed with `Ok`.
+    ///
+    /// Counted when the result is received, so operations that are still running, were
+    /// cancelled, or whose result was dropped by `clear` are not included.
+    pub const fn success_count(&self) -> usize {
+        self.successes
+    }
+
+    /// Returns how many operations completed with `Err`, including failed refreshes whose
+    /// error was hidden by `request_with_stale_fallback`.
+    pub const fn failure_count(&self) -> usize {
+        self.failures
+    }
+
+    /// Resets `count_executed`, `success_count` and `failure_count` to zero.
+    pub const fn reset_stats(&mut self) {
+        self.times_executed = 0;
+        self.successes = 0;
+        self.failures = 0;
+    }
+}
+
+impl<Item: 'static, E: 'static> Bind<Vec<Item>, E> {
+    /// Drives `stream` to its end and finishes with every item it yielded, in order.
+    ///
+    /// The first `Err` item ends the operation with that error; the rest of the stream is
+    /// dropped. While the stream runs, `collected_len` reports how many items have arrived so
+    /// far, and a repaint is requested for each of them. Otherwise this behaves like `request`.
+    ///
     /// This method calls `poll()` internally.
-    pub fn is_pending(&mut self) -> bool {
-        self.poll();
-        matches!(self.state, State::Pending)
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.button("Load all pages").clicked() {
+    ///     records.request_stream_collect(paginated_records(client.clone()));
+    /// }
+    ///
+    /// if let Some(len) = records.collected_len() {
+    ///     ui.label(format!("Loaded {len} records…"));
+    /// }
+    /// ```
+    pub fn request_stream_collect<S>(&mut self, stream: S)
+    where
+        S: futures_core::Stream<Item = Result<Item, E>> + MaybeSend + 'static,
+        Item: MaybeSend,
+        E: MaybeSend,
+    {
+        self.request_with_progress(|progress: ProgressSender<usize>| async move {
+            let mut stream = std::pin::pin!(stream);
+            let mut items = Vec::new();
+            while let Some(item) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                items.push(item?);
+                progress.send(items.len());
+            }
+            Ok(items)
+        });
     }
 
-    /// Checks if the current state is `Finished`.
+    /// Returns how many items the stream of `request_stream_collect` has yielded so far.
+    ///
+    /// Returns `None` before the first item arrives, or if the current operation was not
+    /// started by `request_stream_collect`.
+    ///
     /// This method calls `poll()` internally.
-    pub fn is_finished(&mut self) -> bool {
-        self.poll();
-        matches!(self.state, State::Finished)
+    pub fn collected_len(&mut self) -> Option<usize> {
+        self.progress::<usize>().copied()
     }
+}
 
-    /// Returns `true` if the operation finished during the current `egui` frame.
+#[cfg(feature = "anyhow")]
+impl<T: 'static> Bind<T, anyhow::Error> {
+    /// Starts an asynchronous operation whose error, of any type convertible to
+    /// `anyhow::Error`, is converted inside the task.
+    ///
+    /// This lets apps that standardize on `anyhow` keep every `Bind` as `Bind<T,
+    /// anyhow::Error>` without converting errors in each future. To show the error with its
+    /// causes, use the `Display`-based helpers (e.g. `read_or_error_display`), since the
+    /// `Debug` output of `anyhow::Error` includes a backtrace when one was captured.
+    ///
     /// This method calls `poll()` internally.
-    #[allow(clippy::float_cmp)]
-    pub fn just_completed(&mut self) -> bool {
-        self.poll();
-        self.last_complete_time == CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut config = Bind::<Config, anyhow::Error>::default();
+    /// // Fails with a `std::io::Error`, stored as an `anyhow::Error`.
+    /// config.request_anyhow(async {
+    ///     tokio::fs::read_to_string("config.toml").await.map(|text| parse(&text))
+    /// });
+    /// ```
+    pub fn request_anyhow<Fut, E2>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = Result<T, E2>> + MaybeSend + 'static,
+        E2: Into<anyhow::Error> + 'static,
+        T: MaybeSend,
+    {
+        self.request_map_err(f, Into::into);
     }
+}
 
-    /// If the operation just completed this frame, invokes the provided closure with
-    /// a reference to the result.
-    pub fn on_finished(&mut self, f: impl FnOnce(&Result<T, E>)) {
-        if self.just_completed()
-            && let Some(ref d) = self.data
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+
+    /// Returns a context whose frame clock has been started, so the `Bind`s attached to it
+    /// follow that clock rather than the global one shared by every test.
+    #[cfg(feature = "egui")]
+    fn driven_ctx() -> (egui::Context, Arc<FrameClock>) {
+        let ctx = egui::Context::default();
+        let clock = FrameClock::of(&ctx);
+        clock.tick(0.0);
+        (ctx, clock)
+    }
+
+    /// Drives `clock` through frames at `time` until `bind` leaves `Pending`, so that its
+    /// operation completes at exactly that time.
+    #[cfg(feature = "egui")]
+    fn finish_at<T: 'static, E: 'static>(bind: &mut Bind<T, E>, clock: &FrameClock, time: f64) {
+        let start = std::time::Instant::now();
+        while bind.get_state() == State::Pending {
+            assert!(start.elapsed() < Duration::from_secs(5), "timed out");
+            std::thread::sleep(Duration::from_millis(1));
+            clock.tick(time);
+        }
+    }
+
+    /// Drives `fut` to completion on the runtime selected by the `runtime-*` feature.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        #[cfg(feature = "runtime-tokio")]
+        let output = ASYNC_RUNTIME.block_on(fut);
+        #[cfg(feature = "runtime-async-std")]
+        let output = async_std::task::block_on(fut);
+        #[cfg(feature = "runtime-smol")]
+        let output = smol::block_on(fut);
+        output
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn skipped_frame_clears_data() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder().context(&ctx).build();
+        bind.fill(Ok(1));
+
+        clock.tick(1.0);
+        assert_eq!(bind.read(), &Some(Ok(1)));
+
+        clock.tick(2.0);
+        clock.tick(3.0);
+        assert_eq!(bind.frames_since_drawn(), 2);
+        assert_eq!(bind.read(), &None);
+        assert!(bind.is_idle());
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn polling_every_frame_keeps_data() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder().context(&ctx).build();
+        bind.fill(Ok(1));
+
+        // Two frames may share a timestamp; they still count as separate frames.
+        for time in [1.0, 1.0, 2.0, 3.0] {
+            clock.tick(time);
+            assert_eq!(bind.frames_since_drawn(), 1);
+            assert_eq!(bind.read(), &Some(Ok(1)));
+        }
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn frames_with_equal_times_are_drawn_separately() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder().context(&ctx).build();
+
+        clock.tick(1.0);
+        bind.mark_drawn();
+        assert!(bind.was_drawn_this_frame());
+
+        clock.tick(1.0);
+        assert!(!bind.was_drawn_this_frame());
+        bind.mark_drawn();
+        assert!(bind.was_drawn_this_frame());
+        assert!(bind.was_drawn_last_frame());
+
+        clock.tick(1.0);
+        clock.tick(1.0);
+        bind.mark_drawn();
+        assert!(!bind.was_drawn_last_frame());
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn block_until_finished_advances_only_the_attached_clock() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder()
+            .context(&ctx)
+            .min_pending_duration(Duration::from_millis(50))
+            .build();
+        bind.request(async { Ok(42) });
+
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(42))
+        );
+        assert!(clock.frame_index() > 1);
+        assert!(clock.now() >= 0.05);
+        assert_eq!(frame_index(), 0);
+    }
+
+    #[test]
+    fn block_until_finished_leaves_the_global_clock_alone() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.request(async { Ok(42) });
+
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(42))
+        );
+        assert_eq!(frame_index(), 0);
+    }
+
+    #[test]
+    fn coalesced_requests_share_one_future() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(tokio::sync::Notify::new());
+        let mut binds: Vec<Bind<u32, ()>> = (0..5).map(|_| Bind::new(true)).collect();
+
+        for bind in &mut binds {
+            let runs = runs.clone();
+            let release = release.clone();
+            bind.request_coalesced("share_one_future", move || {
+                runs.fetch_add(1, Ordering::Relaxed);
+                async move {
+                    release.notified().await;
+                    Ok(7)
+                }
+            });
+        }
+        release.notify_one();
+
+        for bind in &mut binds {
+            assert_eq!(
+                bind.block_until_finished(Duration::from_secs(5)),
+                Some(&Ok(7))
+            );
+        }
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn coalesced_request_is_dropped_once_every_bind_cancelled() {
+        /// Records when the shared future is dropped.
+        struct OnDrop(Arc<AtomicBool>);
+        impl Drop for OnDrop {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let mut binds: Vec<Bind<u32, ()>> = (0..3).map(|_| Bind::new(true)).collect();
+        for bind in &mut binds {
+            let dropped = dropped.clone();
+            bind.request_coalesced("dropped_once_every_bind_cancelled", move || async move {
+                let _on_drop = OnDrop(dropped);
+                std::future::pending::<()>().await;
+                Ok(0)
+            });
+        }
+
+        binds[0].cancel();
+        binds[1].cancel();
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(!dropped.load(Ordering::Relaxed));
+
+        binds[2].cancel();
+        let start = std::time::Instant::now();
+        while !dropped.load(Ordering::Relaxed) && start.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(dropped.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn panicking_coalesced_request_frees_its_key() {
+        let mut binds: Vec<Bind<u32, ()>> = (0..3).map(|_| Bind::new(true)).collect();
+        for bind in &mut binds {
+            bind.request_coalesced("panicking_frees_its_key", || async {
+                panic!("the shared request panicked");
+            });
+        }
+
+        for bind in &mut binds {
+            assert_eq!(bind.block_until_finished(Duration::from_secs(5)), None);
+            assert!(bind.is_idle());
+            assert_eq!(bind.try_poll(), Err(PollError::TaskDropped));
+        }
+
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.request_coalesced("panicking_frees_its_key", || async { Ok(1) });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(1))
+        );
+    }
+
+    #[test]
+    fn ready_survives_a_panicking_task() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.fill(Ok(1));
+        bind.request(async { panic!("the request panicked") });
+
+        assert_eq!(block_on(bind.ready()), None);
+        assert_eq!(bind.try_poll(), Err(PollError::TaskDropped));
+        assert_eq!(bind.read(), &Some(Ok(1)));
+
+        bind.request(async { panic!("the request panicked") });
+        let ready = bind.ready_timeout(Duration::from_secs(5));
+        assert_eq!(block_on(ready), None);
+        assert!(!bind.is_pending());
+    }
+
+    #[test]
+    fn tee_forwards_every_error_in_the_same_frame() {
+        let mut source = Bind::<u32, &str>::new(true);
+        let mut mirror = Bind::<u32, ()>::new(true);
+        let mut errors = Vec::new();
+
+        source.request(async { Err("first") });
+        source.block_until_finished(Duration::from_secs(5));
+        source.tee(&mut mirror, |err| errors.push(*err));
+        source.tee(&mut mirror, |err| errors.push(*err));
+
+        source.request(async { Err("second") });
+        source.block_until_finished(Duration::from_secs(5));
+        source.tee(&mut mirror, |err| errors.push(*err));
+
+        source.request(async { Ok(3) });
+        source.block_until_finished(Duration::from_secs(5));
+        source.tee(&mut mirror, |err| errors.push(*err));
+
+        assert_eq!(errors, ["first", "second"]);
+        assert_eq!(mirror.read(), &Some(Ok(3)));
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn local_first_shows_value_at_once_and_counts_only_the_sync() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder()
+            .context(&ctx)
+            .history_capacity(4)
+            .build();
+        let release = Arc::new(tokio::sync::Notify::new());
+
+        let sync_release = release.clone();
+        bind.request_local_first(5, async move {
+            sync_release.notified().await;
+            Ok(())
+        });
+
+        assert_eq!(bind.read(), &Some(Ok(5)));
+        assert_eq!(bind.sync_status(), Some(SyncStatus::Syncing));
+        assert_eq!(bind.success_count(), 0);
+        assert_eq!(bind.failure_count(), 0);
+        assert_eq!(bind.latency_history().len(), 0);
+
+        release.notify_one();
+        let start = std::time::Instant::now();
+        let mut time = 0.0;
+        while bind.sync_status() == Some(SyncStatus::Syncing)
+            && start.elapsed() < Duration::from_secs(5)
         {
-            f(d);
+            time += 0.016;
+            clock.tick(time);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(bind.sync_status(), Some(SyncStatus::Synced));
+        assert_eq!(bind.read(), &Some(Ok(5)));
+        assert_eq!(bind.count_executed(), 1);
+        assert_eq!(bind.success_count(), 1);
+        assert_eq!(bind.latency_history().len(), 0);
+    }
+
+    #[test]
+    fn local_first_keeps_the_value_when_the_sync_fails() {
+        let mut bind = Bind::<u32, &str>::new(true);
+        bind.request_local_first(5, async { Err("offline") });
+
+        let start = std::time::Instant::now();
+        while bind.sync_status() == Some(SyncStatus::Syncing)
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(bind.sync_status(), Some(SyncStatus::Failed));
+        assert_eq!(bind.sync_error(), Some(&"offline"));
+        assert_eq!(bind.read(), &Some(Ok(5)));
+        assert_eq!(bind.success_count(), 0);
+        assert_eq!(bind.failure_count(), 1);
+    }
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn latency_history_keeps_the_latest_durations() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder()
+            .retain(true)
+            .context(&ctx)
+            .history_capacity(2)
+            .build();
+
+        for (start, end) in [(1.0, 1.5), (2.0, 3.0), (4.0, 4.25)] {
+            clock.tick(start);
+            bind.request(async { Ok(1) });
+            finish_at(&mut bind, &clock, end);
         }
+        assert_eq!(bind.latency_history(), [1.0, 0.25]);
+
+        bind.set_history_capacity(1);
+        assert_eq!(bind.latency_history(), [0.25]);
+    }
+
+    #[test]
+    fn fallback_stops_at_the_first_success() {
+        let tried = Arc::new(AtomicUsize::new(0));
+        let source = |result: Result<u32, &'static str>| {
+            let tried = tried.clone();
+            move || async move {
+                tried.fetch_add(1, Ordering::Relaxed);
+                result
+            }
+        };
+
+        let mut bind = Bind::<u32, &str>::new(true);
+        bind.request_fallback(
+            source(Err("cache")),
+            [source(Err("cdn")), source(Ok(3)), source(Ok(4))],
+        );
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(3))
+        );
+        assert_eq!(bind.fallback_source(), Some(2));
+        assert_eq!(tried.load(Ordering::Relaxed), 3);
+
+        bind.request_fallback(source(Err("cache")), [source(Err("origin"))]);
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Err("origin"))
+        );
+        assert_eq!(bind.fallback_source(), None);
+    }
+
+    #[test]
+    fn retry_runs_at_least_once() {
+        let mut bind = Bind::<u32, &str>::new(true);
+        bind.request_retry(|| async { Err("down") }, 0, Duration::ZERO);
+
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Err("down"))
+        );
+        assert_eq!(bind.current_attempt(), 1);
+    }
+
+    #[test]
+    fn optimistic_fill_only_applies_while_pending() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        assert!(!bind.optimistic_fill(1));
+        assert_eq!(bind.read(), &None);
+
+        let release = Arc::new(tokio::sync::Notify::new());
+        let task_release = release.clone();
+        bind.request(async move {
+            task_release.notified().await;
+            Ok(2)
+        });
+        assert!(bind.optimistic_fill(1));
+        assert!(bind.is_optimistic());
+        assert_eq!(bind.read(), &Some(Ok(1)));
+
+        release.notify_one();
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(2))
+        );
+        assert!(!bind.is_optimistic());
+        assert!(!bind.optimistic_fill(3));
+        assert_eq!(bind.read(), &Some(Ok(2)));
     }
 
-    /// Returns `true` if the operation started during the current `egui` frame.
-    /// This method calls `poll()` internally.
-    #[allow(clippy::float_cmp)]
-    pub fn just_started(&mut self) -> bool {
-        self.poll();
-        self.last_start_time == CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+    #[test]
+    fn deps_refetch_only_when_a_dependency_changes() {
+        let runs = AtomicUsize::new(0);
+        let fetch = || {
+            runs.fetch_add(1, Ordering::Relaxed);
+            async { Ok(1) }
+        };
+        let mut bind = Bind::<u32, ()>::new(true);
+        let mut deps = (7, "name", true);
+
+        assert!(bind.request_deps(&deps, fetch));
+        assert!(!bind.request_deps(&deps, fetch));
+
+        deps.1 = "size";
+        assert!(bind.request_deps(&deps, fetch));
+        assert!(!bind.request_deps(&deps, fetch));
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+
+        bind.clear();
+        assert!(bind.request_deps(&deps, fetch));
+        assert_eq!(runs.load(Ordering::Relaxed), 3);
     }
 
-    /// Gets the `egui` time when the operation started.
-    /// This method calls `poll()` internally.
-    pub fn get_start_time(&mut self) -> f64 {
-        self.poll();
-        self.last_start_time
+    #[test]
+    fn stale_fallback_keeps_cached_value_on_error() {
+        let mut bind = Bind::<u32, &str>::new(true);
+        bind.request(async { Ok(1) });
+        bind.block_until_finished(Duration::from_secs(5));
+
+        bind.request_with_stale_fallback(async { Err("offline") });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(1))
+        );
+        assert!(bind.last_refresh_failed());
+
+        bind.request_with_stale_fallback(async { Ok(2) });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(2))
+        );
+        assert!(!bind.last_refresh_failed());
     }
 
-    /// Gets the `egui` time when the operation completed.
-    /// This method calls `poll()` internally.
-    pub fn get_complete_time(&mut self) -> f64 {
-        self.poll();
-        self.last_complete_time
+    #[test]
+    fn stale_fallback_surfaces_error_without_cache() {
+        let mut bind = Bind::<u32, &str>::new(true);
+        bind.request_with_stale_fallback(async { Err("offline") });
+
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Err("offline"))
+        );
+        assert!(!bind.last_refresh_failed());
     }
 
-    /// Gets the duration between the start and completion of the operation.
-    /// This method calls `poll()` internally.
-    pub fn get_elapsed(&mut self) -> f64 {
-        self.poll();
-        self.last_complete_time - self.last_start_time
+    #[test]
+    fn validation_failure_finishes_with_the_validators_error() {
+        let mut bind = Bind::<u32, &str>::new(true);
+        let validate = |value: &u32| if *value > 0 { Ok(()) } else { Err("empty") };
+
+        bind.request_validated(async { Ok(0) }, validate);
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Err("empty"))
+        );
+
+        bind.request_validated(async { Ok(3) }, validate);
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(3))
+        );
     }
 
-    /// Gets the time elapsed since the operation started.
-    /// This method calls `poll()` internally.
-    pub fn since_started(&mut self) -> f64 {
-        self.poll();
-        CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed) - self.last_start_time
+    #[cfg(feature = "egui")]
+    #[test]
+    fn pacer_spreads_a_burst_over_its_rate() {
+        let (ctx, clock) = driven_ctx();
+        let pacer = Pacer::new(5.0);
+        let mut binds: Vec<_> = (0..10)
+            .map(|_| Bind::<u32, ()>::builder().context(&ctx).build())
+            .collect();
+        for (i, bind) in (0..).zip(&mut binds) {
+            bind.request_paced(&pacer, async move { Ok(i) });
+        }
+        assert_eq!(binds[9].paced_position(), Some(9));
+
+        // Step through frames 100ms apart and note the frame on which each request starts.
+        let mut started_on = [None; 10];
+        for frame in 0..30_u32 {
+            clock.tick(f64::from(frame) * 0.1);
+            for (bind, started) in binds.iter_mut().zip(&mut started_on) {
+                if started.is_none() && bind.get_state() != State::Idle {
+                    *started = Some(frame);
+                }
+            }
+        }
+
+        // One start every 200ms: the tenth request starts 1.8s after the first, so the whole
+        // burst takes about two seconds.
+        let expected: Vec<_> = (0..10).map(|i| Some(i * 2)).collect();
+        assert_eq!(started_on.to_vec(), expected);
     }
 
-    /// Gets the time elapsed since the operation completed.
-    /// This method calls `poll()` internally.
-    pub fn since_completed(&mut self) -> f64 {
-        self.poll();
-        CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed) - self.last_complete_time
+    #[cfg(feature = "egui")]
+    #[test]
+    fn auto_retry_waits_for_the_min_error_display() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder().context(&ctx).build();
+        bind.set_min_error_display(2.0);
+        bind.request(async { Err(()) });
+        finish_at(&mut bind, &clock, 0.0);
+
+        let runs = AtomicUsize::new(0);
+        let fetch = || {
+            runs.fetch_add(1, Ordering::Relaxed);
+            async { Ok(1) }
+        };
+
+        // The periodic delay alone has elapsed, but the error has not been shown long enough.
+        clock.tick(1.0);
+        let remaining = bind.request_every_sec(fetch, 0.5);
+        assert!((remaining - 1.0).abs() < 1e-9);
+        assert_eq!(runs.load(Ordering::Relaxed), 0);
+        assert_eq!(bind.read(), &Some(Err(())));
+
+        clock.tick(2.5);
+        bind.request_every_sec(fetch, 0.5);
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(1))
+        );
     }
 
-    /// Returns an immutable reference to the stored data, if any.
-    /// This method calls `poll()` internally.
-    pub fn read(&mut self) -> &Option<Result<T, E>> {
-        self.poll();
-        &self.data
+    #[test]
+    fn swr_shows_the_cached_value_until_the_refresh_lands() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        let release = Arc::new(tokio::sync::Notify::new());
+        let task_release = release.clone();
+        bind.request_swr(Some(Ok(1)), async move {
+            task_release.notified().await;
+            Ok(2)
+        });
+
+        assert!(bind.is_pending());
+        assert_eq!(bind.read(), &Some(Ok(1)));
+
+        release.notify_one();
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(2))
+        );
     }
-    /// Returns an immutable reference in the ref pattern to the stored data, if any.
-    /// This method calls `poll()` internally.
-    pub fn read_as_ref(&mut self) -> Option<Result<&T, &E>> {
-        self.poll();
-        self.data.as_ref().map(Result::as_ref)
+
+    #[test]
+    fn swr_without_a_cached_value_behaves_like_request() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        let release = Arc::new(tokio::sync::Notify::new());
+        let task_release = release.clone();
+        bind.request_swr(None, async move {
+            task_release.notified().await;
+            Ok(2)
+        });
+
+        assert!(bind.is_pending());
+        assert_eq!(bind.read(), &None);
+
+        release.notify_one();
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(2))
+        );
+        assert_eq!(bind.count_executed(), 1);
     }
 
-    /// Returns a mutable reference to the stored data, if any.
-    /// This method calls `poll()` internally.
-    pub fn read_mut(&mut self) -> &mut Option<Result<T, E>> {
-        self.poll();
-        &mut self.data
+    #[test]
+    fn every_subscriber_sees_each_completion_once() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        let mut first = bind.subscribe();
+        let mut second = bind.subscribe();
+        assert!(!first.changed());
+        assert!(!second.changed());
+
+        for value in 0..3 {
+            bind.request(async move { Ok(value) });
+            bind.block_until_finished(Duration::from_secs(5));
+
+            assert!(first.changed());
+            assert!(!first.changed());
+            assert!(second.changed());
+            assert!(!second.changed());
+        }
     }
-    /// Returns a mutable reference in the ref pattern to the stored data, if any.
-    /// This method calls `poll()` internally.
-    pub fn read_as_mut(&mut self) -> Option<Result<&mut T, &mut E>> {
-        self.poll();
-        self.data.as_mut().map(Result::as_mut)
+
+    #[cfg(feature = "egui")]
+    #[test]
+    fn auto_clear_returns_to_idle_while_drawn() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder()
+            .retain(false)
+            .context(&ctx)
+            .build();
+        bind.auto_clear_after(Some(2.0));
+        bind.request(async { Ok(1) });
+        finish_at(&mut bind, &clock, 0.0);
+
+        // Draw every frame, 250ms apart: the result stays for two seconds, then expires.
+        for frame in 1..=8_u32 {
+            clock.tick(f64::from(frame) * 0.25);
+            assert_eq!(bind.get_state(), State::Finished);
+        }
+        clock.tick(2.25);
+        assert_eq!(bind.get_state(), State::Idle);
+        assert_eq!(bind.read(), &None);
     }
 
-    /// Returns the current `State` of the binding.
-    /// This method calls `poll()` internally.
-    pub fn get_state(&mut self) -> State {
-        self.poll();
-        self.state
+    #[test]
+    fn search_latest_shows_only_the_newest_query() {
+        let mut bind = Bind::<&str, ()>::new(true);
+        let release = Arc::new(tokio::sync::Notify::new());
+        let task_release = release.clone();
+        bind.search_latest(async move {
+            task_release.notified().await;
+            Ok("old")
+        });
+        bind.search_latest(async { Ok("new") });
+
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok("new"))
+        );
+
+        // The superseded query was aborted, dropping its handle to `release`.
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while Arc::strong_count(&release) > 1 {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "the old query was not aborted"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        release.notify_one();
+        bind.poll();
+        assert_eq!(bind.read(), &Some(Ok("new")));
+        assert_eq!(bind.count_executed(), 2);
     }
 
-    /// Returns the ref filled state of the `Bind`, allowing for exhaustive pattern matching.
-    ///
-    /// This is often the most ergonomic way to display UI based on the `Bind`'s state.
-    /// This method calls `poll()` internally.
-    ///
-    /// # Example
-    /// ```ignore
-    /// match my_bind.state() {
-    ///     StateWithData::Idle => { /* ... */ }
-    ///     StateWithData::Pending => { ui.spinner(); }
-    ///     StateWithData::Finished(data) => { ui.label(format!("Data: {data:?}")); }
-    ///     StateWithData::Failed(err) => { ui.label(format!("Error: {err:?}")); }
-    /// }
-    /// ```
-    pub fn state(&mut self) -> StateWithData<'_, T, E> {
-        self.poll();
-        match self.state {
-            State::Idle => StateWithData::Idle,
-            State::Pending => StateWithData::Pending,
-            State::Finished => match self.data.as_ref() {
-                Some(Ok(data)) => StateWithData::Finished(data),
-                Some(Err(err)) => StateWithData::Failed(err),
-                None => {
-                    // This case should be unreachable due to internal invariants.
-                    // If state is Finished, data must be Some.
-                    self.state = State::Idle;
-                    StateWithData::Idle
-                }
+    #[cfg(feature = "egui")]
+    #[test]
+    fn quick_failures_exhaust_a_short_budget() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, String>::builder().context(&ctx).build();
+        assert_eq!(bind.remaining_budget(), None);
+
+        // Attempts fail at once and start 100ms apart, so three of them fit in 350ms with
+        // room to spare on a busy machine.
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let task_attempts = attempts.clone();
+        bind.request_with_budget(
+            move || {
+                task_attempts.fetch_add(1, Ordering::Relaxed);
+                async { Err("down".to_owned()) }
             },
-        }
+            Duration::from_millis(350),
+            Duration::from_secs(1),
+        );
+        assert_eq!(bind.remaining_budget(), Some(0.35));
+        clock.tick(0.1);
+        assert!(
+            bind.remaining_budget()
+                .is_some_and(|secs| (secs - 0.25).abs() < 1e-9)
+        );
+
+        let Some(Err(err)) = bind.block_until_finished(Duration::from_secs(5)) else {
+            panic!("the budget was not exhausted");
+        };
+        let attempts = attempts.load(Ordering::Relaxed);
+        assert!(attempts >= 3);
+        assert_eq!(
+            err,
+            &format!("Retry budget exhausted after {attempts} attempt(s): down")
+        );
+        assert_eq!(bind.remaining_budget(), None);
     }
 
-    /// Returns the ref filled state or starts a new request if idle.
-    ///
-    /// This method is an ergonomic way to drive a UI. If the `Bind` is `Idle` and has no
-    /// data, it immediately calls the provided closure `f` to start an async operation,
-    /// transitioning the state to `Pending`.
-    ///
-    /// In all cases, it returns the current `StateWithData` for immediate use in a `match`
-    /// statement, making it easy to display a loading indicator, the finished data, or an error.
-    ///
-    /// # Example
-    /// ```ignore
-    /// // In your UI update function:
-    /// match my_bind.state_or_request(fetch_data) {
-    ///     StateWithData::Idle => { /* This branch is typically not reached on the first call */ }
-    ///     StateWithData::Pending => { ui.spinner(); }
-    ///     StateWithData::Finished(data) => { ui.label(format!("Data: {:?}", data)); }
-    ///     StateWithData::Failed(err) => { ui.label(format!("Error: {:?}", err)); }
-    /// }
-    /// ```
-    pub fn state_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> StateWithData<'_, T, E>
-    where
-        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
-        T: MaybeSend,
-        E: MaybeSend,
-    {
-        self.poll();
+    #[test]
+    fn request_with_args_builds_the_future_from_owned_state() {
+        struct App {
+            greeting: String,
+            repeat: usize,
+            message: Bind<String, ()>,
+        }
 
-        if self.data.is_none() && matches!(self.state, State::Idle) {
-            self.request(f());
+        async fn render(greeting: String, repeat: usize) -> Result<String, ()> {
+            Ok(greeting.repeat(repeat))
         }
-        self.state()
+
+        let mut app = App {
+            greeting: "hi".to_owned(),
+            repeat: 2,
+            message: Bind::new(true),
+        };
+
+        // The app state is only borrowed to build the arguments; the future owns them.
+        app.message
+            .request_with_args((app.greeting.clone(), app.repeat), |(greeting, repeat)| {
+                render(greeting, repeat)
+            });
+        app.greeting.clear();
+
+        assert_eq!(
+            app.message.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok("hihi".to_owned()))
+        );
     }
 
-    /// Clears any stored data and resets the state to `Idle`.
-    ///
-    /// If an operation was `Pending`, its result will be discarded. The background task is not
-    /// cancelled and will run to completion.
-    ///
-    /// This method calls `poll()` internally.
-    pub fn clear(&mut self) {
-        self.poll();
-        self.state = State::Idle;
-        self.data = None;
+    #[test]
+    fn conditional_modified_stores_the_token() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.request_conditional(|token| {
+            assert_eq!(token, None);
+            async { Ok(CondResult::Modified(1, Some("v1".to_owned()))) }
+        });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(1))
+        );
+        assert_eq!(bind.validator_token(), Some("v1"));
+
+        bind.request_conditional(|token| {
+            assert_eq!(token.as_deref(), Some("v1"));
+            async { Ok(CondResult::Modified(2, Some("v2".to_owned()))) }
+        });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(2))
+        );
+        assert_eq!(bind.validator_token(), Some("v2"));
     }
 
-    /// Returns a reference to the data, or starts a new request if idle.
-    ///
-    /// If data is already available (`Finished`), it returns a reference to it.
-    /// If the state is `Idle` and no data is present, it calls `f` to start a new async
-    /// operation and returns `None`.
-    /// If `Pending`, it returns `None`.
-    ///
-    /// This method calls `poll()` internally.
-    pub fn read_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> Option<&Result<T, E>>
-    where
-        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
-        T: MaybeSend,
-        E: MaybeSend,
-    {
-        self.poll();
+    #[cfg(feature = "egui")]
+    #[test]
+    fn conditional_not_modified_keeps_data_and_bumps_completion() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder().context(&ctx).build();
+        bind.request_conditional(|_| async { Ok(CondResult::Modified(1, Some("v1".to_owned()))) });
+        bind.block_until_finished(Duration::from_secs(5));
+        let first_completion = bind.get_complete_time();
 
-        if self.data.is_none() && matches!(self.state, State::Idle) {
-            self.request(f());
-        }
-        self.data.as_ref()
+        clock.tick(first_completion + 1.0);
+        bind.request_conditional(|_| async { Ok(CondResult::NotModified) });
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok(1))
+        );
+        assert!(bind.get_complete_time() >= first_completion + 1.0);
+        assert_eq!(bind.validator_token(), Some("v1"));
+        assert_eq!(bind.count_executed(), 2);
     }
 
-    /// Returns a mutable reference to the data, or starts a new request if idle.
-    ///
-    /// This is the mutable version of `read_or_request`.
-    ///
-    /// This method calls `poll()` internally.
-    pub fn read_mut_or_request<Fut>(&mut self, f: impl FnOnce() -> Fut) -> Option<&mut Result<T, E>>
-    where
-        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
-        T: MaybeSend,
-        E: MaybeSend,
-    {
-        self.poll();
+    #[test]
+    fn conditional_not_modified_without_data_returns_to_idle() {
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.request_conditional(|_| async { Ok(CondResult::NotModified) });
 
-        if self.data.is_none() && matches!(self.state, State::Idle) {
-            self.request(f());
-        }
-        self.data.as_mut()
+        assert_eq!(bind.block_until_finished(Duration::from_secs(5)), None);
+        assert_eq!(bind.get_state(), State::Idle);
     }
 
-    /// Drives the state machine. This should be called once per frame before accessing state.
-    ///
-    /// **Note**: Most other methods on `Bind` call this internally, so you usually don't
-    /// need to call it yourself.
-    ///
-    /// This method performs several key actions:
-    /// 1. Checks if a pending future has completed and, if so, updates the state to `Finished`.
-    /// 2. Updates internal frame timers used for `retain` logic and time tracking.
-    /// 3. If `retain` is `false`, it clears the data if the `Bind` was not polled in the previous frame.
-    ///
-    /// # Panics
-    /// - Panics if the state is `Pending` but the internal receiver is missing. This indicates a bug in `egui-async`.
-    /// - Panics if the `oneshot` channel's sender is dropped without sending a value, which would mean the
-    ///   spawned task terminated unexpectedly.
-    pub fn poll(&mut self) {
-        let curr_frame = CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed);
+    #[test]
+    fn conditional_request_honours_the_default_timeout() {
+        let mut bind = Bind::<u32, ()>::builder()
+            .default_timeout(Duration::from_millis(10))
+            .build();
+        bind.request_conditional(|_| std::future::pending());
 
-        // Avoid re-polling within the same frame.
-        #[allow(clippy::float_cmp)]
-        if curr_frame == self.drawn_time_last {
-            return;
-        }
+        assert_eq!(bind.block_until_finished(Duration::from_secs(5)), None);
+        assert!(bind.timed_out());
+        assert_eq!(bind.get_state(), State::Idle);
+    }
 
-        // Shift frame times for tracking visibility across frames.
-        self.drawn_time_prev = self.drawn_time_last;
-        self.drawn_time_last = curr_frame;
+    #[test]
+    fn mapping_keeps_the_last_key() {
+        let runs = AtomicUsize::new(0);
+        let fetch = || {
+            runs.fetch_add(1, Ordering::Relaxed);
+            async { Ok(2) }
+        };
+        let mut bind = Bind::<u32, ()>::new(true);
+        bind.request_for_key("a", fetch);
+        bind.block_until_finished(Duration::from_secs(5));
 
-        // If `retain` is false and the UI element associated with this `Bind` was not rendered
-        // in the previous frame, we clear its data to free resources and ensure a fresh load.
-        if !self.retain && !self.was_drawn_last_frame() {
-            // Manually clear state to avoid a recursive call to poll() from clear().
-            self.state = State::Idle;
-            self.data = None;
-        }
+        let mut doubled = bind.map(|value| value * 2);
+        assert_eq!(
+            doubled.request_for_key("a", || async { Ok(0) }),
+            &Some(Ok(4))
+        );
+        assert_eq!(doubled.count_executed(), 1);
+        assert_eq!(runs.load(Ordering::Relaxed), 1);
+    }
 
-        if matches!(self.state, State::Pending) {
-            match self
-                .recv
-                .as_mut()
-                .expect("BUG: State is Pending but receiver is missing.")
-                .try_recv()
-            {
-                Ok(result) => {
-                    self.data = Some(result);
-                    self.last_complete_time = CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed);
-                    self.state = State::Finished;
-                    self.recv = None; // Drop the receiver as it's no longer needed.
-                }
-                Err(oneshot::error::TryRecvError::Empty) => {
-                    // Future is still running, do nothing.
-                }
-                Err(oneshot::error::TryRecvError::Closed) => {
-                    // This is a critical error: the task's sender was dropped without sending a value.
-                    // This should only happen if the runtime shuts down unexpectedly.
-                    panic!("Async task's sender was dropped without sending a result.");
+    #[cfg(feature = "egui")]
+    #[test]
+    fn debounced_burst_runs_only_its_first_and_last_request() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<&str, ()>::builder().context(&ctx).build();
+        let ran = Arc::new(Mutex::new(Vec::new()));
+        let release = Arc::new(tokio::sync::Notify::new());
+        let debounce = Duration::from_millis(300);
+
+        let query = |name: &'static str, gate: Option<Arc<tokio::sync::Notify>>| {
+            let ran = ran.clone();
+            move || async move {
+                if let Some(gate) = gate {
+                    gate.notified().await;
                 }
+                ran.lock().unwrap().push(name);
+                Ok(name)
             }
+        };
+
+        bind.request_debounced(query("a", Some(release.clone())), debounce);
+        for (time, name) in [(0.1, "b"), (0.2, "c"), (0.25, "d")] {
+            clock.tick(time);
+            bind.request_debounced(query(name, None), debounce);
         }
-    }
+        assert_eq!(bind.count_executed(), 1);
 
-    /// Checks if this `Bind` has been polled during the current `egui` frame.
-    #[allow(clippy::float_cmp)]
-    pub fn was_drawn_this_frame(&self) -> bool {
-        self.drawn_time_last == CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed)
-    }
+        // The first request keeps running through the burst and its result is shown.
+        release.notify_one();
+        let start = std::time::Instant::now();
+        while bind.get_state() == State::Pending && start.elapsed() < Duration::from_secs(5) {
+            clock.tick(0.3);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(bind.read(), &Some(Ok("a")));
 
-    /// Checks if this `Bind` was polled during the previous `egui` frame.
-    ///
-    /// This is used internally to implement the `retain` logic.
-    #[allow(clippy::float_cmp)]
-    pub fn was_drawn_last_frame(&self) -> bool {
-        self.drawn_time_prev == LAST_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+        // The last request starts once the window after it has passed.
+        clock.tick(0.5);
+        assert_eq!(bind.get_state(), State::Finished);
+        clock.tick(0.6);
+        assert_eq!(bind.get_state(), State::Pending);
+        assert_eq!(
+            bind.block_until_finished(Duration::from_secs(5)),
+            Some(&Ok("d"))
+        );
+        assert_eq!(*ran.lock().unwrap(), ["a", "d"]);
+        assert_eq!(bind.count_executed(), 2);
+        assert_eq!(bind.success_count(), 2);
     }
 
-    /// Returns the total number of times an async operation has been executed.
-    pub const fn count_executed(&self) -> usize {
-        self.times_executed
+    #[cfg(feature = "egui")]
+    #[test]
+    fn remaining_time_counts_down_to_the_default_timeout() {
+        let (ctx, clock) = driven_ctx();
+        let mut bind = Bind::<u32, ()>::builder()
+            .context(&ctx)
+            .default_timeout(Duration::from_secs(10))
+            .build();
+        assert_eq!(bind.remaining_time(), None);
+
+        bind.request(std::future::pending());
+        assert_eq!(bind.remaining_time(), Some(10.0));
+        assert_eq!(bind.remaining_budget(), None);
+
+        clock.tick(4.0);
+        assert_eq!(bind.remaining_time(), Some(6.0));
+
+        bind.cancel();
+        assert_eq!(bind.remaining_time(), None);
     }
 }
@@ -0,0 +1,146 @@
+//! Combining several `Bind`s into one state.
+//!
+//! A `Chain` handles dependent requests, where a second operation needs the result of a first
+//! one. It owns both `Bind`s, so the second request can be started automatically as soon as the
+//! first one succeeds, and restarted whenever the first one produces a new value.
+//!
+//! `join` handles independent requests whose results are only useful together.
+use std::future::Future;
+
+use crate::bind::{Bind, MaybeSend, State, StateWithData};
+
+/// Two `Bind`s where the second request is built from the result of the first.
+///
+/// For example, fetching the user's IP address and then looking up its location. Both steps
+/// share the error type; an error in the first step short-circuits the chain.
+///
+/// # Example
+/// ```ignore
+/// let mut location = Chain::<String, Location, String>::new(true);
+///
+/// match location.state_or_request(fetch_ip, |ip| lookup_location(ip.clone())) {
+///     StateWithData::Idle | StateWithData::Pending => { ui.spinner(); }
+///     StateWithData::Finished(loc) => { ui.label(format!("You are in {}", loc.city)); }
+///     StateWithData::Failed(err) => { ui.colored_label(Color32::RED, err); }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Chain<T, U, E> {
+    first: Bind<T, E>,
+    second: Bind<U, E>,
+}
+
+impl<T: 'static, U: 'static, E: 'static> Default for Chain<T, U, E> {
+    /// Creates a default `Chain` whose `Bind`s do not retain their data.
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<T: 'static, U: 'static, E: 'static> Chain<T, U, E> {
+    /// Creates a new `Chain`; `retain` applies to both `Bind`s, as in `Bind::new`.
+    #[must_use]
+    pub const fn new(retain: bool) -> Self {
+        Self {
+            first: Bind::new(retain),
+            second: Bind::new(retain),
+        }
+    }
+
+    /// Returns the `Bind` of the first step.
+    pub const fn first(&mut self) -> &mut Bind<T, E> {
+        &mut self.first
+    }
+
+    /// Returns the `Bind` of the second step.
+    pub const fn second(&mut self) -> &mut Bind<U, E> {
+        &mut self.second
+    }
+
+    /// Drives both steps and returns the combined state.
+    ///
+    /// If the first step is `Idle`, it is started with `first`. Once it finishes with `Ok`,
+    /// the second step is started with `then`, which receives the first result. If the first
+    /// step is refreshed later, the second one is refreshed as soon as the new value arrives.
+    ///
+    /// The returned state is `Pending` while either step is running, `Failed` with the first
+    /// step's error if it failed (the second step is then not started), and otherwise the
+    /// state of the second step.
+    ///
+    /// This method calls `poll()` internally on both `Bind`s.
+    pub fn state_or_request<Fut1, Fut2>(
+        &mut self,
+        first: impl FnOnce() -> Fut1,
+        then: impl FnOnce(&T) -> Fut2,
+    ) -> StateWithData<'_, U, E>
+    where
+        Fut1: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        Fut2: Future<Output = Result<U, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        U: MaybeSend,
+        E: MaybeSend,
+    {
+        let first_completed = self.first.get_complete_time();
+
+        match self.first.state_or_request(first) {
+            StateWithData::Idle => return StateWithData::Idle,
+            StateWithData::Pending => return StateWithData::Pending,
+            StateWithData::Failed(err) => return StateWithData::Failed(err),
+            StateWithData::Finished(value) => {
+                // Start the second step, or restart it if it was built from an older value.
+                if self.second.get_state() == State::Idle
+                    || self.second.get_start_time() < first_completed
+                {
+                    self.second.refresh(then(value));
+                }
+            }
+        }
+
+        self.second.state()
+    }
+
+    /// Clears both steps. See `Bind::clear`.
+    pub fn clear(&mut self) {
+        self.first.clear();
+        self.second.clear();
+    }
+}
+
+/// The combined state of two `Bind`s, as returned by `join`.
+pub enum JoinedState<'a, T1, T2, E> {
+    /// Neither `Bind` is running, and at least one has no data.
+    Idle,
+    /// At least one `Bind` has an operation in-flight.
+    Pending,
+    /// Both operations have completed successfully.
+    Finished(&'a T1, &'a T2),
+    /// An operation has completed with an error. Holds the first `Bind`'s error if both failed.
+    Failed(&'a E),
+}
+
+/// Polls two independent `Bind`s and combines their states.
+///
+/// The result is `Pending` if either `Bind` is pending, `Failed` if either failed, `Finished`
+/// only when both succeeded, and `Idle` otherwise. No requests are started.
+///
+/// # Example
+/// ```ignore
+/// match join(&mut self.user, &mut self.settings) {
+///     JoinedState::Idle | JoinedState::Pending => { ui.spinner(); }
+///     JoinedState::Finished(user, settings) => { show_profile(ui, user, settings); }
+///     JoinedState::Failed(err) => { ui.colored_label(Color32::RED, err); }
+/// }
+/// ```
+pub fn join<'a, T1: 'static, T2: 'static, E: 'static>(
+    a: &'a mut Bind<T1, E>,
+    b: &'a mut Bind<T2, E>,
+) -> JoinedState<'a, T1, T2, E> {
+    match (a.state(), b.state()) {
+        (StateWithData::Pending, _) | (_, StateWithData::Pending) => JoinedState::Pending,
+        (StateWithData::Failed(err), _) | (_, StateWithData::Failed(err)) => {
+            JoinedState::Failed(err)
+        }
+        (StateWithData::Finished(a), StateWithData::Finished(b)) => JoinedState::Finished(a, b),
+        _ => JoinedState::Idle,
+    }
+}
@@ -4,7 +4,11 @@
 //! `loop_handle()` must be called every frame to update the internal time
 //! and drive the polling mechanism on egui.
 
-use std::fmt::Debug;
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display},
+    sync::{PoisonError, RwLock},
+};
 
 use super::bind::{self, Bind, MaybeSend, State};
 
@@ -13,24 +17,188 @@ pub trait ContextExt {
     /// This must be called every frame to update the internal time
     /// and drive the polling mechanism.
     fn loop_handle(&self);
+
+    /// Like `loop_handle`, but also returns the time, in seconds, since the previous frame
+    /// (see `frame_dt`).
+    ///
+    /// This is handy for animations tied to `Bind` transitions, such as fading in data that
+    /// just finished loading, without tracking `input.time` separately.
+    fn loop_handle_with_dt(&self) -> f64 {
+        self.loop_handle();
+        self.frame_dt()
+    }
+
+    /// Returns the time, in seconds, between the two most recent frames seen by `loop_handle`
+    /// on this context, or `0.0` before the second frame.
+    ///
+    /// Every `Bind` timer runs on the frame clock, which jumps ahead after a long gap between
+    /// frames (e.g. while the window is in the background and repaints are throttled), so
+    /// periodic refreshes fire and `is_stale` flips all at once on the next frame. Use this
+    /// to detect such gaps and adapt.
+    ///
+    /// The default implementation reads the global frame clock; `egui::Context` reads its own.
+    fn frame_dt(&self) -> f64 {
+        if bind::FRAME_INDEX.load(std::sync::atomic::Ordering::Relaxed) > 1 {
+            bind::CURR_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+                - bind::LAST_FRAME.load(std::sync::atomic::Ordering::Relaxed)
+        } else {
+            0.0
+        }
+    }
 }
 
 impl ContextExt for egui::Context {
     fn loop_handle(&self) {
-        bind::CTX.get_or_init(|| self.clone());
-        let time = self.input(|i| i.time);
+        advance_clock(self);
+    }
 
-        let last_frame = bind::CURR_FRAME.swap(time, std::sync::atomic::Ordering::Relaxed);
-        bind::LAST_FRAME.store(last_frame, std::sync::atomic::Ordering::Relaxed);
+    fn frame_dt(&self) -> f64 {
+        let clock = bind::FrameClock::of(self);
+        if clock.frame_index() > 1 {
+            clock.now() - clock.last_frame()
+        } else {
+            0.0
+        }
     }
 }
 
-impl<T: 'static, E: Debug + 'static> Bind<T, E> {
-    /// Reads the data if available, otherwise shows an error popup if there was an error.
-    /// If there was an error, the popup will have a "Retry" button that will trigger the given future.
-    /// If the data is not available, returns None.
-    /// This does NOT automatically request the data if it is not available.
-    pub fn read_or_error<Fut>(&mut self, f: impl FnOnce() -> Fut, ui: &mut egui::Ui) -> Option<&T>
+/// Frame gaps longer than this, in seconds, trigger the one-time hint of `advance_clock`.
+const SKIPPED_FRAMES_WARN_SECS: f64 = 5.0;
+
+/// Whether the hint about skipped frames has been logged.
+static SKIPPED_FRAMES_WARNED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+/// Advances the frame clock of `ctx` to the time of its current frame, unless that already
+/// happened in this `egui` pass, so it is safe to call any number of times per frame.
+///
+/// Frames are told apart by `egui`'s pass number rather than by `input.time`, so two frames
+/// that share a timestamp still count as two.
+///
+/// The global frame clock follows the first context only; other contexts just advance their
+/// own clock, used by the `Bind`s attached to them.
+fn advance_clock(ctx: &egui::Context) {
+    let clock = bind::FrameClock::of(ctx);
+    if !clock.start_pass(ctx.cumulative_pass_nr()) {
+        return;
+    }
+
+    let primary = bind::CTX.get_or_init(|| ctx.clone()) == ctx;
+    let time = ctx.input(|i| i.time);
+
+    clock.tick(time);
+    if clock.frame_index() > 1
+        && clock.now() - clock.last_frame() > SKIPPED_FRAMES_WARN_SECS
+        && !SKIPPED_FRAMES_WARNED.swap(true, std::sync::atomic::Ordering::Relaxed)
+    {
+        tracing::warn!(
+            "No frame was drawn for {:.1}s, so the frame clock and every Bind timer \
+             jumped ahead; see `ContextExt::frame_dt`.",
+            clock.now() - clock.last_frame()
+        );
+    }
+
+    if primary {
+        bind::tick(time);
+    }
+}
+
+impl<T: 'static, E: 'static> Bind<T, E> {
+    /// Attaches the `Bind` to `ctx` and makes sure that context's frame clock is up to date,
+    /// then returns `self`.
+    ///
+    /// This does what `ContextExt::loop_handle` does, but only once per `egui` frame however
+    /// often it is called, so it is a safe way to drive a `Bind` without relying on
+    /// `loop_handle` being called at the top of `update`.
+    ///
+    /// Once attached, the `Bind` follows the frame clock of `ctx` and its tasks request
+    /// repaints from `ctx`, rather than from the first context passed to `loop_handle`. Use
+    /// this (or `BindBuilder::context`) for `Bind`s shown in a secondary window with its own
+    /// `egui::Context`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match self.data.with_ctx(ui.ctx()).state_or_request(fetch_data) {
+    ///     StateWithData::Finished(data) => { ui.label(data); }
+    ///     _ => { ui.spinner(); }
+    /// }
+    /// ```
+    pub fn with_ctx(&mut self, ctx: &egui::Context) -> &mut Self {
+        advance_clock(ctx);
+        self.attach_ctx(ctx);
+        self
+    }
+
+    /// Like `request_every_sec`, but also schedules the repaints needed to keep a countdown to
+    /// the next refresh up to date.
+    ///
+    /// `egui` only repaints on input or when asked to, and this crate only asks when an
+    /// operation completes. This requests a repaint when the next refresh is due, and at every
+    /// whole second until then, so a countdown shown in whole seconds keeps ticking and the
+    /// refresh starts on time without repainting continuously.
+    ///
+    /// # Returns
+    /// The time in seconds remaining until the next scheduled refresh, as `request_every_sec`.
+    pub fn request_every_sec_animated<Fut>(&mut self, f: impl FnOnce() -> Fut, secs: f64) -> f64
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let remaining = self.request_every_sec(f, secs);
+
+        // While `Pending`, the completion requests the repaint instead.
+        if remaining > 0.0
+            && !self.is_pending()
+            && let Some(ctx) = self.ctx()
+        {
+            // Wake up when the countdown reaches the next whole second, or the refresh is due.
+            let until_next_second = remaining.fract();
+            let wait = if until_next_second > 0.0 {
+                until_next_second
+            } else {
+                remaining.min(1.0)
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            ctx.request_repaint_after_secs(wait as f32);
+        }
+
+        remaining
+    }
+}
+
+impl<T: 'static, E: 'static> Bind<T, E> {
+    /// Shows the error popup if the last result was an error, describing it with `describe`.
+    /// Triggers the given future if "Retry" was clicked.
+    /// Returns true if there was an error.
+    fn popup_on_error<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &egui::Ui,
+        describe: impl FnOnce(&E) -> String,
+    ) -> bool
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let Some(Err(e)) = &self.data else {
+            return false;
+        };
+
+        if ui.popup_error(&describe(e)) {
+            self.request(f());
+        }
+        true
+    }
+
+    /// Shared implementation of `read_or_error` and `read_or_error_display`.
+    fn read_or_error_with<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &egui::Ui,
+        describe: impl FnOnce(&E) -> String,
+    ) -> Option<&T>
     where
         Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
         T: MaybeSend,
@@ -38,19 +206,101 @@ impl<T: 'static, E: Debug + 'static> Bind<T, E> {
     {
         self.poll();
 
-        if let Some(Err(e)) = &self.data {
-            let error_string = format!("{e:?}");
-            if ui.popup_error(&error_string) {
-                self.request(f());
-            }
+        if self.popup_on_error(f, ui, describe) {
             None
-        } else if let Some(Ok(data)) = self.data.as_ref() {
-            Some(data)
         } else {
+            self.data.as_ref().and_then(|r| r.as_ref().ok())
+        }
+    }
+
+    /// Shared implementation of `read_mut_or_error` and `read_mut_or_error_display`.
+    fn read_mut_or_error_with<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &egui::Ui,
+        describe: impl FnOnce(&E) -> String,
+    ) -> Option<&mut T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if self.popup_on_error(f, ui, describe) {
             None
+        } else {
+            self.data.as_mut().and_then(|r| r.as_mut().ok())
         }
     }
 
+    /// Shared implementation of `read_or_request_or_error` and
+    /// `read_or_request_or_error_display`.
+    fn read_or_request_or_error_with<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &egui::Ui,
+        describe: impl FnOnce(&E) -> String,
+    ) -> Option<&T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+            None
+        } else if self.popup_on_error(f, ui, describe) {
+            None
+        } else {
+            self.data.as_ref().and_then(|r| r.as_ref().ok())
+        }
+    }
+
+    /// Shared implementation of `read_mut_or_request_or_error` and
+    /// `read_mut_or_request_or_error_display`.
+    fn read_mut_or_request_or_error_with<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &egui::Ui,
+        describe: impl FnOnce(&E) -> String,
+    ) -> Option<&mut T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.poll();
+
+        if matches!(self.state, State::Idle) {
+            self.request_if_online(f);
+            None
+        } else if self.popup_on_error(f, ui, describe) {
+            None
+        } else {
+            self.data.as_mut().and_then(|r| r.as_mut().ok())
+        }
+    }
+}
+
+impl<T: 'static, E: Debug + 'static> Bind<T, E> {
+    /// Reads the data if available, otherwise shows an error popup if there was an error.
+    /// If there was an error, the popup will have a "Retry" button that will trigger the given future.
+    /// If the data is not available, returns None.
+    /// This does NOT automatically request the data if it is not available.
+    ///
+    /// The error is formatted with `Debug`; see `read_or_error_display` for `Display`.
+    pub fn read_or_error<Fut>(&mut self, f: impl FnOnce() -> Fut, ui: &mut egui::Ui) -> Option<&T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.read_or_error_with(f, ui, |e| format!("{e:?}"))
+    }
+
     /// Reads the data mutably if available, otherwise shows an error popup if there was an error.
     /// If there was an error, the popup will have a "Retry" button that will
     /// trigger the given future.
@@ -66,19 +316,7 @@ impl<T: 'static, E: Debug + 'static> Bind<T, E> {
         T: MaybeSend,
         E: MaybeSend,
     {
-        self.poll();
-
-        if let Some(Err(e)) = &self.data {
-            let error_string = format!("{e:?}");
-            if ui.popup_error(&error_string) {
-                self.request(f());
-            }
-            None
-        } else if let Some(Ok(data)) = self.data.as_mut() {
-            Some(data)
-        } else {
-            None
-        }
+        self.read_mut_or_error_with(f, ui, |e| format!("{e:?}"))
     }
 
     /// Reads the data if available, otherwise requests it using the given future.
@@ -96,22 +334,7 @@ impl<T: 'static, E: Debug + 'static> Bind<T, E> {
         T: MaybeSend,
         E: MaybeSend,
     {
-        self.poll();
-
-        if matches!(self.state, State::Idle) {
-            self.request(f());
-            None
-        } else if let Some(Err(e)) = &self.data {
-            let error_string = format!("{e:?}");
-            if ui.popup_error(&error_string) {
-                self.request(f());
-            }
-            None
-        } else if let Some(Ok(data)) = self.data.as_ref() {
-            Some(data)
-        } else {
-            None
-        }
+        self.read_or_request_or_error_with(f, ui, |e| format!("{e:?}"))
     }
 
     /// Reads the data mutably if available, otherwise requests it using the given future.
@@ -129,33 +352,223 @@ impl<T: 'static, E: Debug + 'static> Bind<T, E> {
         T: MaybeSend,
         E: MaybeSend,
     {
-        self.poll();
+        self.read_mut_or_request_or_error_with(f, ui, |e| format!("{e:?}"))
+    }
+}
 
-        if matches!(self.state, State::Idle) {
-            self.request(f());
-            None
-        } else if let Some(Err(e)) = &self.data {
-            let error_string = format!("{e:?}");
-            if ui.popup_error(&error_string) {
-                self.request(f());
-            }
-            None
-        } else if let Some(Ok(data)) = self.data.as_mut() {
-            Some(data)
-        } else {
-            None
-        }
+impl<T: 'static, E: Display + 'static> Bind<T, E> {
+    /// Like `read_or_error`, but formats the error with `Display` instead of `Debug`.
+    ///
+    /// Most error types (e.g. `reqwest::Error`) have a far more readable `Display` output.
+    pub fn read_or_error_display<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &mut egui::Ui,
+    ) -> Option<&T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.read_or_error_with(f, ui, ToString::to_string)
+    }
+
+    /// Like `read_mut_or_error`, but formats the error with `Display` instead of `Debug`.
+    pub fn read_mut_or_error_display<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &mut egui::Ui,
+    ) -> Option<&mut T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.read_mut_or_error_with(f, ui, ToString::to_string)
+    }
+
+    /// Like `read_or_request_or_error`, but formats the error with `Display` instead of
+    /// `Debug`.
+    pub fn read_or_request_or_error_display<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &mut egui::Ui,
+    ) -> Option<&T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.read_or_request_or_error_with(f, ui, ToString::to_string)
+    }
+
+    /// Like `read_mut_or_request_or_error`, but formats the error with `Display` instead of
+    /// `Debug`.
+    pub fn read_mut_or_request_or_error_display<Fut>(
+        &mut self,
+        f: impl FnOnce() -> Fut,
+        ui: &mut egui::Ui,
+    ) -> Option<&mut T>
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        self.read_mut_or_request_or_error_with(f, ui, ToString::to_string)
     }
 }
 
 // After this, it's just some common egui helpers
 // Also serves as more examples.
 
+/// The texts and color of the error popup shown by `UiExt::popup_error_with`.
+///
+/// `popup_error`, and therefore `read_or_error` and friends, use the global default, which can
+/// be replaced with `set_error_popup` (e.g. to localize it).
+///
+/// # Example
+/// ```ignore
+/// set_error_popup(
+///     ErrorPopup::new()
+///         .title("Fehler")
+///         .message("Bitte versuchen Sie es erneut.")
+///         .retry_label("Erneut versuchen"),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorPopup {
+    title: Cow<'static, str>,
+    message: Cow<'static, str>,
+    retry_label: Cow<'static, str>,
+    color: egui::Color32,
+}
+
+impl Default for ErrorPopup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorPopup {
+    /// Creates the default popup: an "Error" window with the error in red and a "Retry" button.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            title: Cow::Borrowed("Error"),
+            message: Cow::Borrowed(
+                "Please retry the request, or contact support if the error persists.",
+            ),
+            retry_label: Cow::Borrowed("Retry"),
+            color: egui::Color32::RED,
+        }
+    }
+
+    /// Sets the window title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<Cow<'static, str>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the message shown below the error. An empty message is not shown.
+    #[must_use]
+    pub fn message(mut self, message: impl Into<Cow<'static, str>>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Sets the label of the retry button.
+    #[must_use]
+    pub fn retry_label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.retry_label = label.into();
+        self
+    }
+
+    /// Sets the color of the error text.
+    #[must_use]
+    pub const fn color(mut self, color: egui::Color32) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+/// The label, tooltips and click debounce of the button drawn by `UiExt::refresh_button_with`.
+///
+/// # Example
+/// ```ignore
+/// let button = RefreshButton::new("Reload data")
+///     .refreshing_text("Reloading…")
+///     .countdown_text(|secs| format!("Next reload in {secs:.0}s"));
+/// ui.refresh_button_with(&mut self.data, fetch, 60.0, &button);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RefreshButton {
+    label: egui::WidgetText,
+    refreshing_text: Cow<'static, str>,
+    countdown_text: fn(f64) -> String,
+    min_manual_interval: Option<f64>,
+}
+
+impl Default for RefreshButton {
+    /// The button used by `refresh_button`: a "🔄" with an English countdown tooltip.
+    fn default() -> Self {
+        Self::new("🔄")
+    }
+}
+
+impl RefreshButton {
+    /// Creates a refresh button with the given label and the default tooltips.
+    #[must_use]
+    pub fn new(label: impl Into<egui::WidgetText>) -> Self {
+        Self {
+            label: label.into(),
+            refreshing_text: Cow::Borrowed("Refreshing now!"),
+            countdown_text: |secs| format!("Refreshing automatically in {secs:.0}s..."),
+            min_manual_interval: None,
+        }
+    }
+
+    /// Sets the tooltip shown right after a refresh was triggered.
+    #[must_use]
+    pub fn refreshing_text(mut self, text: impl Into<Cow<'static, str>>) -> Self {
+        self.refreshing_text = text.into();
+        self
+    }
+
+    /// Sets the tooltip shown while waiting, given the seconds until the next refresh.
+    #[must_use]
+    pub const fn countdown_text(mut self, text: fn(f64) -> String) -> Self {
+        self.countdown_text = text;
+        self
+    }
+
+    /// Sets how many seconds must pass after a completion before a click refreshes again.
+    ///
+    /// Defaults to a quarter of the refresh interval. `0.0` lets every click refresh.
+    #[must_use]
+    pub const fn min_manual_interval(mut self, secs: f64) -> Self {
+        self.min_manual_interval = Some(secs);
+        self
+    }
+}
+
+/// The popup used by `UiExt::popup_error`, set by `set_error_popup`.
+static ERROR_POPUP: RwLock<ErrorPopup> = RwLock::new(ErrorPopup::new());
+
+/// Replaces the popup used by `UiExt::popup_error`, and thereby by `read_or_error` and friends.
+pub fn set_error_popup(popup: ErrorPopup) {
+    *ERROR_POPUP.write().unwrap_or_else(PoisonError::into_inner) = popup;
+}
+
 /// Extension traits for `egui::Ui`
 pub trait UiExt {
     /// Pops up an error window with the given error message.
     /// Returns true if the "Retry" button was clicked.
     fn popup_error(&self, error: &str) -> bool;
+    /// Pops up an error window with the given error message, using the texts and color of
+    /// `popup` instead of the global default.
+    /// Returns true if the retry button was clicked.
+    fn popup_error_with(&self, error: &str, popup: &ErrorPopup) -> bool;
     /// Pops up a notification window with the given info message.
     /// Returns true if the "Ok" button was clicked.
     fn popup_notify(&self, info: &str) -> bool;
@@ -166,6 +579,8 @@ pub trait UiExt {
     /// If the button is clicked, it triggers an immediate refresh, unless the last refresh
     /// was less than `secs / 4` seconds ago, in which case it does nothing.
     /// This is to prevent spamming the refresh button.
+    /// While the operation is `Pending`, the button is disabled and shows a spinner instead of
+    /// its label.
     fn refresh_button<T, E, Fut>(
         &mut self,
         bind: &mut bind::Bind<T, E>,
@@ -175,18 +590,81 @@ pub trait UiExt {
         Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
         T: MaybeSend + 'static,
         E: MaybeSend + 'static;
+
+    /// Like `refresh_button`, but with the label, tooltips and click debounce of `button`.
+    fn refresh_button_with<T, E, Fut>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        f: impl FnOnce() -> Fut,
+        secs: f64,
+        button: &RefreshButton,
+    ) where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+        E: MaybeSend + 'static;
+
+    /// Shows `bind` with default layouts for every state but success, which is drawn by
+    /// `render_ok`.
+    ///
+    /// Nothing is drawn while `Idle`, a spinner while `Pending`, and the error in red once
+    /// `Failed`. Returns what `render_ok` returned, if it was called. Nothing is requested,
+    /// so start the operation beforehand.
+    ///
+    /// # Example
+    /// ```ignore
+    /// if ui.button("Load").clicked() {
+    ///     self.user.request(fetch_user());
+    /// }
+    /// ui.show_bind(&mut self.user, |ui, user| ui.label(&user.name));
+    /// ```
+    fn show_bind<T: 'static, E: Display + 'static, R>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        render_ok: impl FnOnce(&mut egui::Ui, &T) -> R,
+    ) -> Option<R>;
+
+    /// Shows a spinner with the time elapsed, e.g. "Loading… 3.2s", while `bind` is `Pending`.
+    ///
+    /// If the operation has a time limit, set with `BindBuilder::default_timeout` or by
+    /// `Bind::request_with_budget`, a progress bar toward that deadline is drawn below it.
+    ///
+    /// Returns `None`, drawing nothing, if `bind` is not `Pending`.
+    fn async_spinner<T: 'static, E: 'static>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+    ) -> Option<egui::Response>;
+
+    /// Draws a thin timeline strip showing when requests started and completed over the
+    /// last `window` seconds.
+    ///
+    /// Starts are drawn as blue ticks, completions as green (success) or red (error) ticks.
+    /// Useful for spotting thrashing refresh patterns at a glance.
+    ///
+    /// Requires the timing history to be enabled with `Bind::set_history_capacity`.
+    fn request_timeline<T: 'static, E: 'static>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        window: f64,
+    ) -> egui::Response;
 }
 
 const REFRESH_DEBOUNCE_FACTOR: f64 = 4.0;
 
 impl UiExt for egui::Ui {
     fn popup_error(&self, error: &str) -> bool {
+        let popup = ERROR_POPUP
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        self.popup_error_with(error, &popup)
+    }
+    fn popup_error_with(&self, error: &str, popup: &ErrorPopup) -> bool {
         let screen_rect = self.ctx().screen_rect();
         let total_width = screen_rect.width();
         let total_height = screen_rect.height();
 
         let id = egui::Id::new("error_window");
-        egui::Window::new("Error")
+        egui::Window::new(popup.title.as_ref())
             .id(id)
             .collapsible(false)
             .default_width(total_width * 0.25)
@@ -194,15 +672,17 @@ impl UiExt for egui::Ui {
             .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
             .show(self.ctx(), |ui| {
                 ui.vertical_centered(|ui| {
-                    ui.label(egui::RichText::new(error).color(egui::Color32::RED));
+                    ui.label(egui::RichText::new(error).color(popup.color));
 
                     ui.add_space(10.0);
 
-                    ui.label("Please retry the request, or contact support if the error persists.");
+                    if !popup.message.is_empty() {
+                        ui.label(popup.message.as_ref());
 
-                    ui.add_space(10.0);
+                        ui.add_space(10.0);
+                    }
 
-                    ui.button("Retry").clicked()
+                    ui.button(popup.retry_label.as_ref()).clicked()
                 })
                 .inner
             })
@@ -243,10 +723,46 @@ impl UiExt for egui::Ui {
         T: MaybeSend + 'static,
         E: MaybeSend + 'static,
     {
-        let resp = self.button("🔄");
+        self.refresh_button_with(bind, f, secs, &RefreshButton::default());
+    }
+
+    fn refresh_button_with<T, E, Fut>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        f: impl FnOnce() -> Fut,
+        secs: f64,
+        button: &RefreshButton,
+    ) where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+        E: MaybeSend + 'static,
+    {
+        if bind.is_pending() {
+            // Nothing useful can be done while a refresh is running. The label is kept (but not
+            // drawn) so the button does not change size, and a spinner takes its place.
+            let resp = self.add_enabled(
+                false,
+                egui::Button::new(button.label.clone().color(egui::Color32::TRANSPARENT)),
+            );
+            let size = resp.rect.height() - 2.0 * self.spacing().button_padding.y;
+            egui::Spinner::new().paint_at(
+                self,
+                egui::Rect::from_center_size(resp.rect.center(), egui::Vec2::splat(size)),
+            );
+            bind.request_every_sec(f, secs);
+            resp.on_disabled_hover_text(button.refreshing_text.to_string());
+            return;
+        }
+
+        let resp = self.button(button.label.clone());
 
-        // Only actually refresh when clicked if the last completion was more than 1/4 of the interval ago
-        let diff = if bind.since_completed() > secs / REFRESH_DEBOUNCE_FACTOR && resp.clicked() {
+        // Only actually refresh when clicked if the last completion was more than 1/4 of the
+        // interval ago, unless the button sets its own threshold.
+        let min_interval = button
+            .min_manual_interval
+            .unwrap_or(secs / REFRESH_DEBOUNCE_FACTOR);
+        let debounced = min_interval > 0.0 && bind.since_completed() <= min_interval;
+        let diff = if !debounced && resp.clicked() {
             bind.refresh(f());
             -1.0
         } else {
@@ -254,9 +770,107 @@ impl UiExt for egui::Ui {
         };
 
         resp.on_hover_text(if diff < 0.0 {
-            "Refreshing now!".to_string()
+            button.refreshing_text.to_string()
         } else {
-            format!("Refreshing automatically in {diff:.0}s...")
+            (button.countdown_text)(diff)
         });
     }
+
+    fn show_bind<T: 'static, E: Display + 'static, R>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        render_ok: impl FnOnce(&mut egui::Ui, &T) -> R,
+    ) -> Option<R> {
+        match bind.state() {
+            bind::StateWithData::Idle => None,
+            bind::StateWithData::Pending => {
+                self.spinner();
+                None
+            }
+            bind::StateWithData::Finished(data) => Some(render_ok(self, data)),
+            bind::StateWithData::Failed(err) => {
+                self.colored_label(egui::Color32::RED, err.to_string());
+                None
+            }
+        }
+    }
+
+    fn async_spinner<T: 'static, E: 'static>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+    ) -> Option<egui::Response> {
+        if !bind.is_pending() {
+            return None;
+        }
+
+        let elapsed = bind.since_started();
+        let remaining = bind.remaining_time();
+
+        let resp = self
+            .vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(format!("Loading… {elapsed:.1}s"));
+                });
+
+                if let Some(remaining) = remaining {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let fraction = (elapsed / (elapsed + remaining).max(f64::EPSILON)) as f32;
+                    ui.add(egui::ProgressBar::new(fraction).text(format!("{remaining:.0}s left")));
+                }
+            })
+            .response;
+
+        Some(resp)
+    }
+
+    fn request_timeline<T: 'static, E: 'static>(
+        &mut self,
+        bind: &mut bind::Bind<T, E>,
+        window: f64,
+    ) -> egui::Response {
+        const HEIGHT: f32 = 12.0;
+
+        let (rect, resp) = self.allocate_exact_size(
+            egui::vec2(self.available_width(), HEIGHT),
+            egui::Sense::hover(),
+        );
+
+        let painter = self.painter_at(rect);
+        painter.rect_filled(rect, 2.0, self.visuals().extreme_bg_color);
+
+        let now = bind.now();
+        #[allow(clippy::cast_possible_truncation)]
+        let x_at = |time: f64| {
+            let age = ((now - time) / window) as f32;
+            (0.0..=1.0)
+                .contains(&age)
+                .then(|| rect.right() - age * rect.width())
+        };
+        let tick = |x: f32, color: egui::Color32| {
+            painter.vline(x, rect.y_range(), egui::Stroke::new(1.5, color));
+        };
+
+        for timing in bind.history() {
+            if let Some(x) = x_at(timing.start_time) {
+                tick(x, egui::Color32::LIGHT_BLUE);
+            }
+            if let Some(x) = x_at(timing.complete_time) {
+                let color = if timing.succeeded {
+                    egui::Color32::GREEN
+                } else {
+                    egui::Color32::RED
+                };
+                tick(x, color);
+            }
+        }
+
+        if bind.is_pending()
+            && let Some(x) = x_at(bind.get_start_time())
+        {
+            tick(x, egui::Color32::LIGHT_BLUE);
+        }
+
+        resp
+    }
 }
@@ -2,9 +2,46 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 
+#[cfg(all(
+    not(target_family = "wasm"),
+    not(any(
+        feature = "runtime-tokio",
+        feature = "runtime-async-std",
+        feature = "runtime-smol"
+    ))
+))]
+compile_error!(
+    "egui-async needs an async runtime on native targets: enable one of the `runtime-tokio`, \
+     `runtime-async-std` or `runtime-smol` features."
+);
+
+#[cfg(any(
+    all(feature = "runtime-tokio", feature = "runtime-async-std"),
+    all(feature = "runtime-tokio", feature = "runtime-smol"),
+    all(feature = "runtime-async-std", feature = "runtime-smol")
+))]
+compile_error!(
+    "The `runtime-tokio`, `runtime-async-std` and `runtime-smol` features are mutually \
+     exclusive; set `default-features = false` to replace the default `runtime-tokio`."
+);
+
 pub mod bind;
+pub mod chain;
+#[cfg(feature = "test-util")]
+pub mod mock;
+pub mod pacer;
+pub mod queue;
+pub mod stream;
 
-pub use bind::{Bind, State, StateWithData};
+pub use bind::{
+    BackpressureMode, Bind, BindBuilder, BindSubscription, BudgetExhausted, CondResult,
+    ConnectionStatus, PollError, ProgressSender, State, StateSnapshot, StateWithData, Step,
+    SyncStatus,
+};
+pub use chain::{Chain, JoinedState, join};
+pub use pacer::Pacer;
+pub use queue::BindQueue;
+pub use stream::BindStream;
 
 #[cfg(feature = "egui")]
 pub mod egui;
@@ -26,3 +63,20 @@ macro_rules! run_once {
         init_once_block_executed
     }};
 }
+
+/// A macro to poll several `Bind`s (or `BindStream`s) in one call.
+///
+/// Calling this at the top of `update` drives every listed `Bind` each frame, regardless of
+/// which UI branches are rendered. Note that polling counts as "drawn": a `Bind` with
+/// `retain = false` that is polled here every frame is never cleared for being off-screen.
+///
+/// # Example
+/// ```ignore
+/// poll_all!(self.users, self.settings, self.avatar);
+/// ```
+#[macro_export]
+macro_rules! poll_all {
+    ($($bind:expr),* $(,)?) => {{
+        $( $bind.poll(); )*
+    }};
+}
@@ -0,0 +1,100 @@
+//! Scripted responses for testing UI code that uses `Bind`.
+//!
+//! This module is only compiled with the `test-util` feature, which is meant to be enabled in
+//! `dev-dependencies` only. Without it, `Bind::request` always spawns the real future and none
+//! of this exists, so release builds are unaffected.
+//!
+//! A global handler is consulted by every `Bind::request` (and the methods built on it, such
+//! as `refresh` and `read_or_request`). It sees the `Bind`'s name (see `Bind::set_name`) and
+//! may return a canned `MockResponse`, in which case nothing is spawned. The response is
+//! delivered on the first poll at or after its delay, measured on the frame clock
+//! (`bind::CURR_FRAME`), so tests can drive whole flows (loading, success, error) frame by
+//! frame.
+//!
+//! # Example
+//! ```ignore
+//! use egui_async::mock::{MockResponse, set_mock_handler};
+//!
+//! set_mock_handler(|request| match request.name {
+//!     Some("user") => Some(MockResponse::new(Ok::<_, String>("Alice".to_string())).after(0.5)),
+//!     Some("avatar") => Some(MockResponse::new(Err::<Vec<u8>, _>("404".to_string()))),
+//!     _ => None, // Everything else hits the real future.
+//! });
+//! ```
+use std::{
+    any::Any,
+    sync::{PoisonError, RwLock},
+};
+
+/// Describes an intercepted request, as passed to the mock handler.
+#[derive(Clone, Copy, Debug)]
+pub struct MockRequest<'a> {
+    /// The name of the `Bind`, if one was set with `Bind::set_name`.
+    pub name: Option<&'a str>,
+    /// The type name of the `Bind`'s result, `Result<T, E>`.
+    pub type_name: &'static str,
+}
+
+/// A canned result returned by the mock handler instead of running the real future.
+#[derive(Debug)]
+pub struct MockResponse {
+    /// The type-erased `Result<T, E>`.
+    result: Box<dyn Any + Send>,
+    /// Seconds on the frame clock before the result is delivered.
+    delay: f64,
+}
+
+impl MockResponse {
+    /// Creates a response that is delivered on the next poll.
+    ///
+    /// `T` and `E` must match the intercepted `Bind` exactly; otherwise the response is
+    /// ignored (with a warning) and the real future runs.
+    #[must_use]
+    pub fn new<T: Send + 'static, E: Send + 'static>(result: Result<T, E>) -> Self {
+        Self {
+            result: Box::new(result),
+            delay: 0.0,
+        }
+    }
+
+    /// Delays the delivery by `secs` seconds on the frame clock.
+    #[must_use]
+    pub const fn after(mut self, secs: f64) -> Self {
+        self.delay = secs;
+        self
+    }
+
+    /// Unpacks the response for a `Bind<T, E>`, or returns `None` if the types do not match.
+    pub(crate) fn into_result<T: 'static, E: 'static>(self) -> Option<(Result<T, E>, f64)> {
+        let result = self.result.downcast::<Result<T, E>>().ok()?;
+        Some((*result, self.delay))
+    }
+}
+
+type MockHandler = Box<dyn Fn(&MockRequest<'_>) -> Option<MockResponse> + Send + Sync>;
+
+/// The global mock handler, set by `set_mock_handler`.
+static MOCK_HANDLER: RwLock<Option<MockHandler>> = RwLock::new(None);
+
+/// Installs a global handler that may answer requests with canned responses.
+///
+/// The handler returns `None` to let a request run normally.
+pub fn set_mock_handler(
+    handler: impl Fn(&MockRequest<'_>) -> Option<MockResponse> + Send + Sync + 'static,
+) {
+    *MOCK_HANDLER.write().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(handler));
+}
+
+/// Removes the global mock handler, so every request runs normally again.
+pub fn clear_mock_handler() {
+    *MOCK_HANDLER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Asks the mock handler for a response to `request`.
+pub(crate) fn respond(request: &MockRequest<'_>) -> Option<MockResponse> {
+    MOCK_HANDLER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .and_then(|handler| handler(request))
+}
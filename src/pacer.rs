@@ -0,0 +1,67 @@
+//! Rate limiting for request starts.
+//!
+//! A `Pacer` is shared between many `Bind`s and admits their requests at a fixed rate, which
+//! smooths out spikes such as a "refresh all" button triggering dozens of requests in one frame.
+//! Unlike a concurrency limit, it caps how often requests *start*, not how many run at once.
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A shared rate limiter that admits request starts at a fixed rate.
+///
+/// Cloning a `Pacer` is cheap and yields a handle to the same schedule.
+///
+/// # Example
+/// ```ignore
+/// let pacer = Pacer::new(5.0); // At most 5 request starts per second.
+///
+/// if ui.button("Refresh all").clicked() {
+///     for bind in &mut self.rows {
+///         bind.request_paced(&pacer, fetch_row());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pacer {
+    inner: Arc<Mutex<PacerInner>>,
+}
+
+#[derive(Debug)]
+struct PacerInner {
+    /// Seconds between two admitted requests.
+    interval: f64,
+    /// The `egui` time of the next free slot.
+    next_slot: f64,
+}
+
+impl Pacer {
+    /// Creates a new `Pacer` admitting at most `per_second` requests per second.
+    ///
+    /// # Panics
+    /// Panics if `per_second` is not strictly positive.
+    #[must_use]
+    pub fn new(per_second: f64) -> Self {
+        assert!(per_second > 0.0, "Pacer rate must be positive.");
+
+        Self {
+            inner: Arc::new(Mutex::new(PacerInner {
+                interval: per_second.recip(),
+                next_slot: f64::MIN,
+            })),
+        }
+    }
+
+    /// Returns the number of seconds between two admitted requests.
+    pub fn interval(&self) -> f64 {
+        self.inner
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .interval
+    }
+
+    /// Reserves the next free slot at or after `now`, returning its `egui` time.
+    pub(crate) fn reserve(&self, now: f64) -> f64 {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let slot = inner.next_slot.max(now);
+        inner.next_slot = slot + inner.interval;
+        slot
+    }
+}
@@ -0,0 +1,109 @@
+//! Several operations of the same kind, each tracked on its own.
+//!
+//! A `Bind` tracks a single operation at a time. When the UI lets the user start any number of
+//! them, such as one upload per selected file, a `BindQueue` keeps one `Bind` per operation so
+//! that every row can show its own progress and result.
+use std::future::Future;
+
+use crate::bind::{Bind, MaybeSend, State, StateWithData};
+
+/// A growing list of independently tracked operations.
+///
+/// Every pushed future is spawned right away, with the same completion and repaint handling as
+/// `Bind::request`. Its results are retained until `clear` is called, whether or not they are
+/// drawn.
+///
+/// # Example
+/// ```ignore
+/// let mut uploads = BindQueue::<String, String>::new();
+///
+/// for path in dropped_files {
+///     uploads.push(upload(path));
+/// }
+///
+/// for (i, state) in uploads.iter().enumerate() {
+///     match state {
+///         StateWithData::Idle | StateWithData::Pending => { ui.label(format!("#{i}: uploading…")); }
+///         StateWithData::Finished(msg) => { ui.label(format!("#{i}: {msg}")); }
+///         StateWithData::Failed(err) => { ui.colored_label(Color32::RED, format!("#{i}: {err}")); }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BindQueue<T, E> {
+    binds: Vec<Bind<T, E>>,
+}
+
+impl<T: 'static, E: 'static> Default for BindQueue<T, E> {
+    /// Creates an empty `BindQueue`.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static, E: 'static> BindQueue<T, E> {
+    /// Creates an empty `BindQueue`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { binds: Vec::new() }
+    }
+
+    /// Starts `f` and returns the index under which it is tracked.
+    pub fn push<Fut>(&mut self, f: Fut) -> usize
+    where
+        Fut: Future<Output = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        let mut bind = Bind::new(true);
+        bind.request(f);
+        self.binds.push(bind);
+        self.binds.len() - 1
+    }
+
+    /// Returns the `Bind` tracking the operation at `index`, if there is one.
+    pub fn get(&mut self, index: usize) -> Option<&mut Bind<T, E>> {
+        self.binds.get_mut(index)
+    }
+
+    /// Returns the state of every operation, in the order they were pushed.
+    ///
+    /// This method calls `poll()` internally on every `Bind`.
+    pub fn iter(&mut self) -> impl Iterator<Item = StateWithData<'_, T, E>> {
+        self.binds.iter_mut().map(Bind::state)
+    }
+
+    /// Returns `true` if every operation has finished with a result (success or error).
+    ///
+    /// This method calls `poll()` internally on every `Bind`.
+    pub fn all_finished(&mut self) -> bool {
+        self.binds
+            .iter_mut()
+            .all(|bind| bind.get_state() == State::Finished)
+    }
+
+    /// Returns `true` if at least one operation is still running.
+    ///
+    /// This method calls `poll()` internally on every `Bind`.
+    pub fn any_pending(&mut self) -> bool {
+        self.binds.iter_mut().any(Bind::is_pending)
+    }
+
+    /// Returns the number of operations pushed since the last `clear`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.binds.len()
+    }
+
+    /// Returns `true` if no operation was pushed since the last `clear`.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.binds.is_empty()
+    }
+
+    /// Forgets every operation. Operations still running finish in the background, but their
+    /// results are discarded. Indices start from zero again.
+    pub fn clear(&mut self) {
+        self.binds.clear();
+    }
+}
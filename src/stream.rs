@@ -0,0 +1,340 @@
+//! State management for operations that produce results incrementally.
+//!
+//! A `Bind` models a single result delivered through a oneshot channel. Operations such as a
+//! log tail, a download reporting progress, or a token stream from a language model instead
+//! yield many values over time. `BindStream` drives such a `Stream` in the background and
+//! collects its items, frame by frame, just like `Bind` does for a single result.
+use std::{fmt::Debug, future::Future};
+
+use futures_core::Stream;
+
+use crate::bind::{
+    BackpressureMode, LiveQueue, MaybeSend, Repainter, frame_index, request_repaint, spawn,
+};
+
+/// A state manager for an asynchronous operation that yields a stream of results.
+///
+/// Depending on how it is created, a `BindStream` either keeps only the latest item (e.g. a
+/// progress value) or accumulates every item (e.g. lines of a log).
+///
+/// # Example
+/// ```ignore
+/// let mut tokens = BindStream::<String, String>::new(true);
+///
+/// if ui.button("Ask").clicked() {
+///     tokens.request_stream(async { llm_token_stream(prompt).await });
+/// }
+///
+/// let text: String = tokens.items().iter().filter_map(|t| t.as_ref().ok()).cloned().collect();
+/// ui.label(text);
+/// if !tokens.is_complete() {
+///     ui.spinner();
+/// }
+/// ```
+pub struct BindStream<T, E> {
+    /// Receives the items of the running stream. `None` when no stream is running.
+    queue: Option<LiveQueue<Result<T, E>>>,
+    /// How items that arrive faster than the UI polls are handled. `None` takes in every
+    /// queued item on each poll.
+    backpressure: Option<BackpressureMode>,
+    /// The received items. Holds only the latest one unless `accumulate` is set.
+    items: Vec<Result<T, E>>,
+    /// Whether every item is kept, rather than only the latest.
+    accumulate: bool,
+    /// Whether the latest stream has ended.
+    complete: bool,
+    /// The `FRAME_INDEX` of the most recent frame in which this was polled.
+    drawn_on_frame: u64,
+    /// The number of streams started.
+    times_executed: usize,
+}
+
+impl<T, E> Debug for BindStream<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Avoid printing the items themselves for cleaner debug output.
+        f.debug_struct("BindStream")
+            .field("queue", &self.queue.as_ref().map(|_| "..."))
+            .field("backpressure", &self.backpressure)
+            .field("items", &self.items.len())
+            .field("accumulate", &self.accumulate)
+            .field("complete", &self.complete)
+            .field("drawn_on_frame", &self.drawn_on_frame)
+            .field("times_executed", &self.times_executed)
+            .finish()
+    }
+}
+
+impl<T: 'static, E: 'static> Default for BindStream<T, E> {
+    /// Creates a `BindStream` that keeps only the latest item.
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<T: 'static, E: 'static> BindStream<T, E> {
+    /// Creates a new, idle `BindStream`.
+    ///
+    /// # Parameters
+    /// - `accumulate`: If `true`, every item is kept and available through `items`. If
+    ///   `false`, each item replaces the previous one.
+    #[must_use]
+    pub const fn new(accumulate: bool) -> Self {
+        Self {
+            queue: None,
+            backpressure: None,
+            items: Vec::new(),
+            accumulate,
+            complete: false,
+            drawn_on_frame: 0,
+            times_executed: 0,
+        }
+    }
+
+    /// Sets how items that arrive faster than the UI polls are delivered. Takes effect for
+    /// streams started afterwards.
+    ///
+    /// By default, every item that arrived since the last poll is taken in at once. With a
+    /// `BackpressureMode`, at most one item is taken in per frame: `KeepLatest` skips to the
+    /// newest item, `KeepAll` buffers a bounded backlog, and `Block` makes the stream wait
+    /// until its previous item has been taken.
+    pub const fn set_backpressure(&mut self, mode: BackpressureMode) {
+        self.backpressure = Some(mode);
+    }
+
+    /// Starts a stream and collects its items in the background.
+    ///
+    /// `f` resolves to the `Stream` (e.g. after connecting), which is then driven to its end.
+    /// A repaint is requested for every item. Any previous stream is stopped and its items are
+    /// cleared.
+    pub fn request_stream<Fut, S>(&mut self, f: Fut)
+    where
+        Fut: Future<Output = S> + MaybeSend + 'static,
+        S: Stream<Item = Result<T, E>> + MaybeSend + 'static,
+        T: MaybeSend,
+        E: MaybeSend,
+    {
+        // Without a mode, the queue is unbounded and is drained directly.
+        let (tx, queue) =
+            LiveQueue::new(self.backpressure.unwrap_or_default(), Repainter::default());
+
+        spawn(async move {
+            let mut stream = std::pin::pin!(f.await);
+            while let Some(item) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                if !tx.send(item).await {
+                    // The `BindStream` was cleared or dropped; stop consuming the stream.
+                    return;
+                }
+                request_repaint();
+            }
+            // Dropping `tx` marks the stream as complete; make sure that gets drawn.
+            drop(tx);
+            request_repaint();
+        });
+
+        self.queue = Some(queue);
+        self.items.clear();
+        self.complete = false;
+        self.times_executed += 1;
+    }
+
+    /// Collects the items that arrived since the last poll. Runs at most once per frame, or
+    /// on every call while the frame clock is not driven (e.g. in a headless test).
+    ///
+    /// Every accessor calls this internally, so there is usually no need to call it directly.
+    pub fn poll(&mut self) {
+        let curr_frame = frame_index();
+
+        if curr_frame > 0 && curr_frame == self.drawn_on_frame {
+            return;
+        }
+        self.drawn_on_frame = curr_frame;
+
+        let Some(queue) = &mut self.queue else {
+            return;
+        };
+
+        let mut push = |item| {
+            if !self.accumulate {
+                self.items.clear();
+            }
+            self.items.push(item);
+        };
+        if self.backpressure.is_some() {
+            // At most one item per frame, as chosen by the `BackpressureMode`.
+            if let Some(item) = queue.next() {
+                push(item);
+            }
+        } else {
+            while let Some(item) = queue.try_recv() {
+                push(item);
+            }
+        }
+
+        if queue.is_drained() {
+            self.queue = None;
+            self.complete = true;
+        }
+    }
+
+    /// Returns the most recent item, if any has arrived.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn latest(&mut self) -> Option<&Result<T, E>> {
+        self.poll();
+        self.items.last()
+    }
+
+    /// Returns the received items: all of them if accumulating, otherwise at most the latest.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn items(&mut self) -> &[Result<T, E>] {
+        self.poll();
+        &self.items
+    }
+
+    /// Returns `true` if a stream is running.
+    ///
+    /// This method calls `poll()` internally.
+    pub fn is_pending(&mut self) -> bool {
+        self.poll();
+        self.queue.is_some()
+    }
+
+    /// Returns `true` if the latest stream has ended, as of the last poll.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Returns how many streams have been started.
+    #[must_use]
+    pub const fn count_executed(&self) -> usize {
+        self.times_executed
+    }
+
+    /// Stops the running stream, if any, and clears all items.
+    ///
+    /// The background task stops at its next item.
+    pub fn clear(&mut self) {
+        self.queue = None;
+        self.items.clear();
+        self.complete = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        task::Poll,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+
+    /// Starts an accumulating `BindStream` with `mode` over a burst of five items. The returned
+    /// flag is set once the stream has handed its last item over.
+    fn burst(mode: BackpressureMode) -> (BindStream<u32, ()>, Arc<AtomicBool>) {
+        let sent = Arc::new(AtomicBool::new(false));
+        let task_sent = sent.clone();
+
+        let mut stream = BindStream::new(true);
+        stream.set_backpressure(mode);
+        stream.request_stream(async move {
+            use futures::StreamExt as _;
+
+            futures::stream::iter((1..=5).map(Ok)).chain(futures::stream::poll_fn(move |_| {
+                task_sent.store(true, Ordering::Release);
+                Poll::Ready(None)
+            }))
+        });
+        (stream, sent)
+    }
+
+    /// Waits until `flag` is set.
+    fn wait_for(flag: &AtomicBool) {
+        let start = Instant::now();
+        while !flag.load(Ordering::Acquire) {
+            assert!(start.elapsed() < Duration::from_secs(5), "timed out");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Polls `stream` until it completes, returning how many items each poll took in.
+    fn poll_to_end(stream: &mut BindStream<u32, ()>) -> Vec<usize> {
+        let start = Instant::now();
+        let mut taken = Vec::new();
+        while !stream.is_complete() {
+            assert!(start.elapsed() < Duration::from_secs(5), "timed out");
+            let before = stream.items.len();
+            stream.poll();
+            taken.push(stream.items.len() - before);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        taken
+    }
+
+    /// Streams two items and an error into a new `BindStream` and waits for the end.
+    fn drain(accumulate: bool) -> BindStream<u32, ()> {
+        let mut stream = BindStream::new(accumulate);
+        stream.request_stream(async { futures::stream::iter([Ok(1), Ok(2), Err(())]) });
+
+        let start = Instant::now();
+        while !stream.is_complete() && start.elapsed() < Duration::from_secs(5) {
+            stream.poll();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        stream
+    }
+
+    #[test]
+    fn accumulating_stream_keeps_every_item() {
+        let mut stream = drain(true);
+        assert!(stream.is_complete());
+        assert!(!stream.is_pending());
+        assert_eq!(stream.items(), [Ok(1), Ok(2), Err(())]);
+    }
+
+    #[test]
+    fn latest_only_stream_keeps_the_last_item() {
+        let mut stream = drain(false);
+        assert!(stream.is_complete());
+        assert_eq!(stream.items(), [Err(())]);
+        assert_eq!(stream.latest(), Some(&Err(())));
+    }
+
+    #[test]
+    fn keep_latest_skips_to_the_newest_item_of_a_burst() {
+        let (mut stream, sent) = burst(BackpressureMode::KeepLatest);
+        wait_for(&sent);
+
+        poll_to_end(&mut stream);
+        assert_eq!(stream.items(), [Ok(5)]);
+    }
+
+    #[test]
+    fn keep_all_drops_the_oldest_items_beyond_its_cap() {
+        let (mut stream, sent) = burst(BackpressureMode::KeepAll(2));
+        wait_for(&sent);
+
+        let taken = poll_to_end(&mut stream);
+        assert!(taken.iter().all(|&count| count <= 1));
+        assert_eq!(stream.items(), [Ok(4), Ok(5)]);
+    }
+
+    #[test]
+    fn block_delivers_one_item_per_frame_without_losses() {
+        let (mut stream, sent) = burst(BackpressureMode::Block);
+
+        // The stream cannot run ahead of the polls, so it has not finished sending.
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!sent.load(Ordering::Acquire));
+
+        let taken = poll_to_end(&mut stream);
+        assert!(taken.iter().all(|&count| count <= 1));
+        assert_eq!(stream.items(), [Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+    }
+}